@@ -1,38 +1,26 @@
 advent_of_code::solution!(4);
 
-use std::vec;
-
 use advent_of_code::{Direction, Grid, Point};
 
-const DIRECTIONS: [Direction; 8] = [
-    Direction::South,
-    Direction::East,
-    Direction::SouthWest,
-    Direction::SouthEast,
-    Direction::West,
-    Direction::NorthWest,
-    Direction::North,
-    Direction::NorthEast,
-];
+/// Every line is scanned forwards only, so backwards words are searched for reversed.
+const PATTERNS: [&str; 2] = ["XMAS", "SAMX"];
 
 pub fn part_one(input: &str) -> Option<u64> {
     let grid = parse_input(input);
-    let xmas = vec!['X', 'M', 'A', 'S'];
-    let mut total = 0;
-
-    for (point, value) in grid.iter().with_points() {
-        if value != 'X' {
-            continue;
-        }
 
-        for direction in DIRECTIONS {
-            if grid.matches(point, direction, &xmas) {
-                total += 1;
-            }
-        }
-    }
+    let total = [Direction::East, Direction::South, Direction::SouthEast, Direction::SouthWest]
+        .into_iter()
+        .flat_map(|direction| grid.lines(direction))
+        .map(|(_, line)| {
+            let line: String = line.collect();
+            PATTERNS
+                .iter()
+                .map(|pattern| line.matches(pattern).count())
+                .sum::<usize>()
+        })
+        .sum::<usize>();
 
-    Some(total)
+    Some(total as u64)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
@@ -64,31 +52,11 @@ pub fn part_two(input: &str) -> Option<u64> {
 }
 
 pub fn parse_input(input: &str) -> Grid<char> {
-    let vec: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-    Grid::new(vec)
+    Grid::parse(input).unwrap()
 }
 
 fn get_corner_values(grid: &Grid<char>, point: &Point) -> [u64; 4] {
-    let corners = [
-        Point {
-            x: point.x - 1,
-            y: point.y - 1,
-        }, // Top left
-        Point {
-            x: point.x + 1,
-            y: point.y - 1,
-        }, // Top right
-        Point {
-            x: point.x - 1,
-            y: point.y + 1,
-        }, // Bottom left
-        Point {
-            x: point.x + 1,
-            y: point.y + 1,
-        }, // Bottom right
-    ];
-
-    corners.map(|p| grid.get(p) as u64)
+    Direction::DIAGONAL.map(|direction| grid.get(point.translate_direction(direction)) as u64)
 }
 
 #[cfg(test)]
@@ -1,140 +1,97 @@
-use advent_of_code::{Direction, Grid, Point};
-use std::collections::HashSet;
+use advent_of_code::{par_map_reduce, CursorStop, Direction, Grid, GridCursor, Point};
 
 advent_of_code::solution!(6);
 
-const DIRECTIONS: [Direction; 4] =
-    [Direction::North, Direction::East, Direction::South, Direction::West];
+advent_of_code::cell_enum! {
+    pub enum Tile {
+        '.' => Open,
+        '#' => Wall,
+        '^' => Guard,
+    }
+}
+
+advent_of_code::layered_grid! {
+    pub struct Lab {
+        walls: bool {
+            set: set_wall,
+            replace: replace_walls,
+            render: |&wall| wall.then_some('#'),
+        },
+        visited: bool {
+            set: set_visited,
+            replace: replace_visited,
+            render: |&visited| visited.then_some('X'),
+        },
+    }
+}
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (start, walls) = parse_input(input);
-    let visited_cells = visited_route(start, &walls);
+    let (start, mut lab) = parse_input(input);
+    visit_route(start, &mut lab);
 
-    let total = visited_cells.iter().filter(|&x| x).count();
+    let total = lab.visited().count(true);
     Some(total as u64)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (start, mut grid) = parse_input(input);
-    let visited_cells = visited_route(start, &grid);
-
-    let total = visited_cells
-        .iter()
-        .with_points()
-        .filter(|(current, value)| (*value && *current != start))
-        .filter(|(current, _)| {
-            grid.set(*current, true);
-            let count = is_circular_route(start, &grid);
-            grid.set(*current, false);
-
-            count
-        })
-        .count() as u64;
-
-    Some(total)
-}
-
-/// Returns true if the route is circular
-type PointProcessor<T> = fn(Point, Direction, &mut T) -> bool;
-
-pub fn walk_route<T>(
-    start: Point,
-    walls: &Grid<bool>,
-    state: &mut T,
-    processor: PointProcessor<T>,
-) {
-    let mut next = start;
-
-    'outer: for direction in DIRECTIONS.iter().cycle() {
-        let mut iter = walls.iter().in_direction(*direction, next).with_points();
-
-        loop {
-            if let Some((current, wall)) = iter.next() {
-                if wall {
-                    break;
-                }
-                if processor(current, *direction, state) {
-                    break 'outer;
-                }
-                next = current;
-            } else {
-                break 'outer;
-            }
-        }
-    }
-}
+    let (start, mut lab) = parse_input(input);
+    visit_route(start, &mut lab);
+    let candidates: Vec<Point> = lab
+        .visited()
+        .find_all(true)
+        .into_iter()
+        .filter(|&point| point != start)
+        .collect();
 
-pub fn visited_route(start: Point, walls: &Grid<bool>) -> Grid<bool> {
-    let mut visited_cells = Grid::<bool>::new_empty(walls.width, walls.height);
+    let total = par_map_reduce(
+        &candidates,
+        lab.walls(),
+        0,
+        |grid, &current| {
+            grid.set(current, true);
+            let is_circular = is_circular_route(start, grid);
+            grid.set(current, false);
 
-    walk_route(
-        start,
-        walls,
-        &mut visited_cells,
-        |point, _, visited_cells| {
-            visited_cells.set(point, true);
-            false
+            is_circular as u64
         },
+        |a, b| a + b,
     );
 
-    visited_cells
+    Some(total)
 }
 
-pub fn is_circular_route(start: Point, walls: &Grid<bool>) -> bool {
-    struct Package {
-        visited: HashSet<(Point, Direction)>,
-        is_circular: bool,
-    }
-
-    fn process_point(point: Point, direction: Direction, state: &mut Package) -> bool {
-        if state.visited.contains(&(point, direction)) {
-            state.is_circular = true;
-            return true;
+/// Lets the guard walk, turning right at every wall, until it leaves the lab or (with revisit
+/// detection) starts walking in circles.
+fn patrol(guard: &mut GridCursor, walls: &Grid<bool>) -> CursorStop {
+    loop {
+        match guard.step_until_blocked(walls, |&wall| wall) {
+            CursorStop::Blocked => guard.turn_right(),
+            stop => return stop,
         }
-        state.visited.insert((point, direction));
-        false
     }
+}
 
-    let capacity = (walls.width * walls.height / 2) as usize;
-    let visited = HashSet::<(Point, Direction)>::with_capacity(capacity);
-    let is_circular = false;
-    let mut package = Package {
-        visited,
-        is_circular,
-    };
+pub fn visit_route(start: Point, lab: &mut Lab) {
+    let mut guard = GridCursor::new(start, Direction::North).with_trail();
+    patrol(&mut guard, lab.walls());
 
-    walk_route(start, walls, &mut package, process_point);
+    for &point in guard.trail() {
+        lab.set_visited(point, true);
+    }
+}
 
-    package.is_circular
+pub fn is_circular_route(start: Point, walls: &Grid<bool>) -> bool {
+    let mut guard = GridCursor::new(start, Direction::North).with_revisit_detection();
+    patrol(&mut guard, walls) == CursorStop::Revisit
 }
 
-pub fn parse_input(input: &str) -> (Point, Grid<bool>) {
-    let mut player = Point { x: 0, y: 0 };
-
-    let walls: Vec<Vec<bool>> = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(x, c)| {
-                    if c == '#' {
-                        return true;
-                    } else if c == '^' {
-                        player = Point {
-                            x: x as isize,
-                            y: y as isize,
-                        };
-                        return false;
-                    }
-                    false
-                })
-                .collect()
-        })
-        .collect();
+pub fn parse_input(input: &str) -> (Point, Lab) {
+    let map = Grid::<Tile>::parse(input).unwrap();
+    let player = map.find(Tile::Guard).unwrap();
+    let walls = map.map(|tile| tile == Tile::Wall);
+    let lab = Lab::from_layers(walls, Grid::new_empty(map.width, map.height)).unwrap();
 
-    let walls_grid = Grid::new(walls);
-    (player, walls_grid)
+    (player, lab)
 }
 
 #[cfg(test)]
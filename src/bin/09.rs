@@ -20,9 +20,9 @@ pub fn part_one(input: &str) -> Option<u64> {
         }
         let entry = input[i];
 
-        if entry.id.is_some() {
+        if let Some(id) = entry.id {
             for _ in 0..entry.size {
-                checksum += entry.id.unwrap() * count;
+                checksum += id * count;
                 count += 1;
             }
         } else {
@@ -1,23 +1,14 @@
-use advent_of_code::{Direction, Grid, Point};
+use advent_of_code::{Grid, Neighborhood, Point};
 
 advent_of_code::solution!(10);
 
-const DIRECTIONS: [Direction; 4] =
-    [Direction::North, Direction::South, Direction::East, Direction::West];
-
 pub fn part_one(input: &str) -> Option<u64> {
     let input = parse_input(input);
 
     let total: usize = input
-        .iter()
-        .with_points()
-        .filter_map(|(point, v)| {
-            if v == 0 {
-                let count = walk_path(&input, point);
-                return Some(count);
-            }
-            None
-        })
+        .find_all(0)
+        .into_iter()
+        .map(|point| walk_path(&input, point))
         .sum();
     Some(total as u64)
 }
@@ -26,15 +17,9 @@ pub fn part_two(input: &str) -> Option<u64> {
     let input = parse_input(input);
 
     let total: usize = input
-        .iter()
-        .with_points()
-        .filter_map(|(point, v)| {
-            if v == 0 {
-                let count = walk_path_rated(&input, point);
-                return Some(count);
-            }
-            None
-        })
+        .find_all(0)
+        .into_iter()
+        .map(|point| walk_path_rated(&input, point))
         .sum();
     Some(total as u64)
 }
@@ -76,21 +61,18 @@ where
         return;
     }
 
-    DIRECTIONS.iter().for_each(|direction| {
-        let next = point.translate_direction(*direction);
-        if grid.get_safe(next) == Some(current + 1) {
-            walk_path_recur(grid, next, current + 1, count_function);
-        }
-    });
+    grid.neighbors(point, Neighborhood::Cardinal)
+        .for_each(|(_, next, &value)| {
+            if value == current + 1 {
+                walk_path_recur(grid, next, current + 1, count_function);
+            }
+        });
 }
 
 pub fn parse_input(input: &str) -> Grid<u32> {
-    let vec: Vec<Vec<u32>> = input
-        .lines()
-        .map(|line| line.chars())
-        .map(|chars| chars.map(|c| c.to_digit(10).unwrap()).collect())
-        .collect();
-    Grid::new(vec)
+    Grid::<char>::parse(input)
+        .unwrap()
+        .map(|c| c.to_digit(10).unwrap())
 }
 
 #[cfg(test)]
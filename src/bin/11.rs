@@ -42,7 +42,7 @@ pub fn blink(stone: Stone, cache: &mut HashMap<Stone, usize>) -> usize {
 
     let answer = if stone.number == 0 {
         blink(stone.next(1), cache)
-    } else if count_digits % 2 == 0 {
+    } else if count_digits.is_multiple_of(2) {
         let (half_a, half_b) = stone.split(count_digits / 2);
         blink(half_a, cache) + blink(half_b, cache)
     } else {
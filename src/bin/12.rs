@@ -1,57 +1,58 @@
 advent_of_code::solution!(12);
-use advent_of_code::{Direction, Grid};
+use advent_of_code::{Grid, Neighborhood};
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let input = parse_input(input);
+    let mut input = parse_input(input);
+    let points: Vec<_> = input.iter().with_points().map(|(point, _)| point).collect();
+    let mut total = 0;
 
-    let results = input.iter().with_points().filter_map(|(point, plot)| {
+    for point in points {
+        let plot = input.get(point);
         if plot.checked {
-            return None;
+            continue;
         }
 
         let points = input.get_cluster(point);
 
-        let mut fence = 0;
         let surface = points.len() as u64;
+        let fence = points
+            .iter()
+            .flat_map(|&point| input.neighbors_or(point, Neighborhood::Cardinal, &OUTSIDE))
+            .filter(|(_, _, neighbor)| neighbor.plant != plot.plant)
+            .count() as u64;
 
         for point in points {
-            for direction in DIRECTIONS {
-                let next = point.translate_direction(direction);
-                let val = input.get_safe(next);
-                if val.is_none() || val.unwrap().plant != plot.plant {
-                    fence += 1;
-                }
-            }
+            input.set(
+                point,
+                Plot {
+                    checked: true,
+                    ..plot
+                },
+            );
         }
 
-
-        Some(fence * surface)
-
-    });
-    Some(results.sum())
+        total += fence * surface;
+    }
+    Some(total)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(_input: &str) -> Option<u64> {
     None
 }
 
-const DIRECTIONS: [Direction; 4] =  [
-    Direction::North,
-    Direction::East,
-    Direction::South,
-    Direction::West,
-];
+const OUTSIDE: Plot = Plot {
+    checked: false,
+    plant: '.',
+};
 
 pub fn parse_input(input: &str) -> Grid<Plot> {
     let data = input
         .lines()
         .map(|line| {
             line.chars()
-                .map(|c| {
-                    Plot {
-                        checked: false,
-                        plant: c,
-                    }
+                .map(|c| Plot {
+                    checked: false,
+                    plant: c,
                 })
                 .collect::<Vec<_>>()
         })
@@ -62,9 +63,8 @@ pub fn parse_input(input: &str) -> Grid<Plot> {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Plot {
-	checked: bool,
+    checked: bool,
     plant: char,
-
 }
 
 #[cfg(test)]
@@ -0,0 +1,173 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// Returns the lowest total cost from `start` to any node matching `is_goal`, together with
+/// that node. `successors` returns the neighbours of a node and the cost to reach each.
+pub fn dijkstra<N, I, F, G>(start: N, mut successors: F, mut is_goal: G) -> Option<(N, usize)>
+where
+    N: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (N, usize)>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    let mut best = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+    while let Some(Reverse((cost, node))) = queue.pop() {
+        if is_goal(&node) {
+            return Some((node, cost));
+        }
+        if best.get(&node).is_some_and(|&b| b < cost) {
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if best.get(&next).is_none_or(|&b| next_cost < b) {
+                best.insert(next.clone(), next_cost);
+                queue.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// A weighted edge between two nodes of a [`Graph`], carrying an optional payload such as
+/// the cells of a corridor.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Edge<P = ()> {
+    pub to: usize,
+    pub length: usize,
+    pub path: P,
+}
+
+/// A directed graph with weighted edges, whose nodes are addressed by index. Every edge
+/// carries a payload of type `P`.
+#[derive(Debug, Clone)]
+pub struct Graph<N, P = ()> {
+    pub nodes: Vec<N>,
+    pub edges: Vec<Vec<Edge<P>>>,
+    index: HashMap<N, usize>,
+}
+
+impl<N, P> Default for Graph<N, P> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+}
+
+impl<N, P> Graph<N, P>
+where
+    N: Clone + Eq + Hash,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node if it does not exist yet and returns its index.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&index) = self.index.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.index.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+        index
+    }
+
+    pub fn add_edge(&mut self, from: usize, edge: Edge<P>) {
+        self.edges[from].push(edge);
+    }
+
+    pub fn node_index(&self, node: &N) -> Option<usize> {
+        self.index.get(node).copied()
+    }
+
+    /// Returns the target index and length of every edge leaving `from`.
+    pub fn successors(&self, from: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.edges[from].iter().map(|edge| (edge.to, edge.length))
+    }
+
+    pub fn shortest_path(&self, from: &N, to: &N) -> Option<usize> {
+        let (from, to) = (self.node_index(from)?, self.node_index(to)?);
+        dijkstra(from, |&node| self.successors(node), |&node| node == to).map(|(_, cost)| cost)
+    }
+
+    /// Returns the length of the longest path from `from` to `to` that visits every node at
+    /// most once. This is an exhaustive search, so it is only feasible for small graphs such
+    /// as compressed mazes.
+    pub fn longest_path(&self, from: &N, to: &N) -> Option<usize> {
+        let (from, to) = (self.node_index(from)?, self.node_index(to)?);
+        let mut visited = vec![false; self.nodes.len()];
+        self.longest_path_recur(from, to, &mut visited)
+    }
+
+    fn longest_path_recur(&self, from: usize, to: usize, visited: &mut [bool]) -> Option<usize> {
+        if from == to {
+            return Some(0);
+        }
+
+        visited[from] = true;
+        let longest = self
+            .successors(from)
+            .filter_map(|(next, length)| {
+                if visited[next] {
+                    return None;
+                }
+                self.longest_path_recur(next, to, visited)
+                    .map(|rest| rest + length)
+            })
+            .max();
+        visited[from] = false;
+
+        longest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dijkstra() {
+        // 0 -> 1 costs 4, 0 -> 2 -> 1 costs 1 + 2.
+        let edges: HashMap<u32, Vec<(u32, usize)>> =
+            HashMap::from([(0, vec![(1, 4), (2, 1)]), (2, vec![(1, 2)]), (1, vec![(3, 1)])]);
+        let successors = |n: &u32| edges.get(n).cloned().unwrap_or_default();
+
+        assert_eq!(dijkstra(0, successors, |&n| n == 1), Some((1, 3)));
+        assert_eq!(dijkstra(0, successors, |&n| n == 3), Some((3, 4)));
+        assert_eq!(dijkstra(0, successors, |&n| n == 9), None);
+    }
+
+    #[test]
+    fn test_shortest_and_longest_path() {
+        let mut graph = Graph::new();
+        let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|n| graph.add_node(n));
+        let edge = |to, length| Edge {
+            to,
+            length,
+            path: (),
+        };
+        graph.add_edge(a, edge(b, 1));
+        graph.add_edge(a, edge(c, 5));
+        graph.add_edge(b, edge(c, 1));
+        graph.add_edge(c, edge(b, 10));
+        graph.add_edge(b, edge(d, 1));
+        graph.add_edge(c, edge(d, 1));
+
+        assert_eq!(graph.add_node('a'), a);
+        assert_eq!(graph.shortest_path(&'a', &'d'), Some(2));
+        // a -> c -> b -> d
+        assert_eq!(graph.longest_path(&'a', &'d'), Some(16));
+        assert_eq!(graph.longest_path(&'d', &'a'), None);
+        assert_eq!(graph.shortest_path(&'a', &'z'), None);
+    }
+}
@@ -1,23 +1,43 @@
-use crate::{Direction, GridIterator, Point};
+use crate::{Direction, GridError, GridIterator, Neighborhood, Point, Rect};
 use std::fmt::Debug;
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
 pub struct Grid<T> {
     pub width: isize,
     pub height: isize,
-    cells: Vec<Vec<T>>,
+    pub(super) cells: Vec<Vec<T>>,
 }
 
 impl<T> Grid<T>
 where
     T: Copy + PartialEq + Debug + Default,
 {
+    /// Creates a grid from rows of cells.
+    ///
+    /// Panics if the input is empty or ragged, see [`Grid::try_new`].
     pub fn new(input: Vec<Vec<T>>) -> Grid<T> {
-        Self {
-            width: input[0].len() as isize,
+        Self::try_new(input).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_new(input: Vec<Vec<T>>) -> Result<Grid<T>, GridError> {
+        let width = match input.first() {
+            Some(row) if !row.is_empty() => row.len(),
+            _ => return Err(GridError::Empty),
+        };
+
+        if let Some((row, cells)) = input.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(GridError::RaggedRow {
+                row,
+                expected: width,
+                found: cells.len(),
+            });
+        }
+
+        Ok(Self {
+            width: width as isize,
             height: input.len() as isize,
             cells: input,
-        }
+        })
     }
 
     pub fn new_empty(width: isize, height: isize) -> Grid<T> {
@@ -33,6 +53,7 @@ where
     }
 
     pub fn get(&self, point: Point) -> T {
+        self.check_bounds(point);
         self.cells[point.y as usize][point.x as usize]
     }
 
@@ -45,6 +66,7 @@ where
     }
 
     pub fn set(&mut self, point: Point, value: T) {
+        self.check_bounds(point);
         self.cells[point.y as usize][point.x as usize] = value;
     }
 
@@ -70,6 +92,18 @@ where
     }
 
     pub fn get_values(&self, start: Point, direction: Direction, distance: usize) -> Vec<T> {
+        self.try_get_values(start, direction, distance)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns `distance` values starting at `start`, or the first point that falls
+    /// outside the grid.
+    pub fn try_get_values(
+        &self,
+        start: Point,
+        direction: Direction,
+        distance: usize,
+    ) -> Result<Vec<T>, GridError> {
         let values: Vec<T> = self
             .iter()
             .in_direction(direction, start)
@@ -77,33 +111,68 @@ where
             .collect();
 
         if values.len() != distance {
-            panic!("Out of bounds");
+            let (dx, dy) = direction.delta();
+            let steps = values.len() as isize;
+            return Err(self.out_of_bounds_error(start.offset(dx * steps, dy * steps)));
         }
-        values
+        Ok(values)
+    }
+
+    /// Returns the rectangle covered by the grid, from `(0, 0)` to the bottom right cell.
+    pub fn bounds(&self) -> Rect {
+        Rect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: self.width - 1,
+                y: self.height - 1,
+            },
+        )
     }
 
     pub fn out_of_bounds(&self, point: &Point) -> bool {
-        if point.x < 0 || point.x >= self.width {
-            return true;
+        !self.bounds().contains(point)
+    }
+
+    fn check_bounds(&self, point: Point) {
+        if self.out_of_bounds(&point) {
+            panic!("{}", self.out_of_bounds_error(point));
         }
+    }
 
-        if point.y < 0 || point.y >= self.height {
-            return true;
+    fn out_of_bounds_error(&self, point: Point) -> GridError {
+        GridError::OutOfBounds {
+            point,
+            width: self.width,
+            height: self.height,
         }
-        false
     }
 
     /// Returns a vector of points that are part of the cluster
     /// starting from the given point.
     /// The cluster is defined as all points that are adjacent to the starting point
     /// in the cardinal directions (N, S, E, W) and have the same value as the starting point.
-    ///
-    /// !! The given point must be at the heighest point of the cluster for performance reasons.
     pub fn get_cluster(&self, point: Point) -> Vec<Point> {
-        unimplemented!()
+        let value = self.get(point);
+        let mut seen = Grid::<bool>::new_empty(self.width, self.height);
+        let mut stack = vec![point];
+        let mut cluster = Vec::new();
+        seen.set(point, true);
+
+        while let Some(current) = stack.pop() {
+            cluster.push(current);
+
+            for (_, next, &neighbor) in self.neighbors(current, Neighborhood::Cardinal) {
+                if neighbor == value && !seen.get(next) {
+                    seen.set(next, true);
+                    stack.push(next);
+                }
+            }
+        }
+
+        cluster
     }
 
-    pub fn iter(&self) -> GridIterator<T> {
+    pub fn iter(&self) -> GridIterator<'_, T> {
         GridIterator::new(self)
     }
 }
@@ -119,6 +188,37 @@ mod tests {
         assert_eq!(grid.height, 3);
     }
 
+    #[test]
+    pub fn test_try_new_grid() {
+        let grid = Grid::try_new(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+        assert_eq!(grid.width, 2);
+        assert_eq!(grid.height, 3);
+
+        assert_eq!(Grid::<u8>::try_new(vec![]), Err(GridError::Empty));
+        assert_eq!(Grid::<u8>::try_new(vec![vec![]]), Err(GridError::Empty));
+        assert_eq!(
+            Grid::try_new(vec![vec![1, 2], vec![3], vec![5, 6]]),
+            Err(GridError::RaggedRow {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has 3 cells, expected 2 like the first row")]
+    pub fn test_new_grid_ragged() {
+        Grid::new(vec![vec![1, 2], vec![3, 4, 5]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "point (3, 0) is out of bounds for a 3x3 grid")]
+    pub fn test_get_out_of_bounds() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        grid.get(Point { x: 3, y: 0 });
+    }
+
     #[test]
     pub fn test_out_of_bounds() {
         let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
@@ -216,4 +316,58 @@ mod tests {
         let direction = Direction::East;
         grid.get_values(point, direction, 4);
     }
+
+    #[test]
+    pub fn test_get_cluster() {
+        let grid = Grid::new(vec![
+            vec![1, 0, 1, 2],
+            vec![1, 0, 1, 0],
+            vec![1, 1, 1, 0],
+            vec![0, 2, 0, 2],
+        ]);
+
+        // The flood fill reaches the left arm of the U through its bottom, even when it
+        // starts at the top of the right arm.
+        let mut u_shape = grid.get_cluster(Point { x: 2, y: 0 });
+        u_shape.sort_by_key(|p| (p.y, p.x));
+        assert_eq!(
+            u_shape,
+            vec![
+                Point { x: 0, y: 0 },
+                Point { x: 2, y: 0 },
+                Point { x: 0, y: 1 },
+                Point { x: 2, y: 1 },
+                Point { x: 0, y: 2 },
+                Point { x: 1, y: 2 },
+                Point { x: 2, y: 2 },
+            ]
+        );
+
+        // (3, 2) and (2, 3) only touch diagonally.
+        let mut right = grid.get_cluster(Point { x: 3, y: 1 });
+        right.sort_by_key(|p| (p.y, p.x));
+        assert_eq!(right, vec![Point { x: 3, y: 1 }, Point { x: 3, y: 2 }]);
+        assert_eq!(
+            grid.get_cluster(Point { x: 2, y: 3 }),
+            vec![Point { x: 2, y: 3 }]
+        );
+    }
+
+    #[test]
+    pub fn test_try_get_values() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let point = Point { x: 1, y: 0 };
+        assert_eq!(
+            grid.try_get_values(point, Direction::SouthEast, 2),
+            Ok(vec![2, 6])
+        );
+        assert_eq!(
+            grid.try_get_values(point, Direction::SouthEast, 3),
+            Err(GridError::OutOfBounds {
+                point: Point { x: 3, y: 2 },
+                width: 3,
+                height: 3
+            })
+        );
+    }
 }
@@ -0,0 +1,318 @@
+use crate::{Grid, GridError, Point};
+use std::fmt::Debug;
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Creates a grid filled with `fill`, with `mark` at every one of `points`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of `points` lies outside of the `width` by `height` grid.
+    pub fn from_points<I>(points: I, width: isize, height: isize, fill: T, mark: T) -> Grid<T>
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let mut grid = Grid::from_fn(width, height, |_| fill);
+        for point in points {
+            grid.set(point, mark);
+        }
+        grid
+    }
+
+    /// Starts a grid filled with `fill` whose size is inferred from the points marked on it.
+    pub fn builder(fill: T) -> GridBuilder<T> {
+        GridBuilder {
+            fill,
+            width: None,
+            height: None,
+            marks: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GridBuilder<T> {
+    fill: T,
+    width: Option<isize>,
+    height: Option<isize>,
+    marks: Vec<(Point, T)>,
+}
+
+impl<T> GridBuilder<T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Fixes the width instead of inferring it from the points.
+    pub fn width(mut self, width: isize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Fixes the height instead of inferring it from the points.
+    pub fn height(mut self, height: isize) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets every one of `points` to `value`. Later marks overwrite earlier ones.
+    pub fn mark<I>(mut self, points: I, value: T) -> Self
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        self.marks
+            .extend(points.into_iter().map(|point| (point, value)));
+        self
+    }
+
+    /// Builds the grid. Unless fixed, the size is the smallest that includes the origin
+    /// and every marked point, so a builder without marks gives a single cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a marked point has a negative coordinate, as grids start at the origin, or
+    /// lies outside of a fixed width or height. Also panics if a fixed width or height is not
+    /// positive, as grids are never empty.
+    pub fn build(self) -> Grid<T> {
+        if let Some((point, _)) = self.marks.iter().find(|(p, _)| p.x < 0 || p.y < 0) {
+            panic!(
+                "cannot build a grid with the negative point ({}, {})",
+                point.x, point.y
+            );
+        }
+
+        let width = self
+            .width
+            .unwrap_or_else(|| self.marks.iter().map(|(p, _)| p.x + 1).max().unwrap_or(1));
+        let height = self
+            .height
+            .unwrap_or_else(|| self.marks.iter().map(|(p, _)| p.y + 1).max().unwrap_or(1));
+        if width <= 0 || height <= 0 {
+            panic!("{}", GridError::Empty);
+        }
+
+        let mut grid = Grid::from_fn(width, height, |_| self.fill);
+        for (point, value) in self.marks {
+            grid.set(point, value);
+        }
+        grid
+    }
+}
+
+/// A grid that has a prefix of a list of points applied to it, for puzzles where points
+/// arrive one by one (e.g. falling bytes).
+#[derive(Debug, Clone)]
+pub struct PointStream<T> {
+    grid: Grid<T>,
+    points: Vec<Point>,
+    applied: usize,
+    fill: T,
+    mark: T,
+}
+
+impl<T> PointStream<T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Creates a `width` by `height` grid filled with `fill`, with none of `points` applied.
+    pub fn new<I>(points: I, width: isize, height: isize, fill: T, mark: T) -> Self
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        Self {
+            grid: Grid::from_fn(width, height, |_| fill),
+            points: points.into_iter().collect(),
+            applied: 0,
+            fill,
+            mark,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// The number of points applied to the grid so far.
+    pub fn applied(&self) -> usize {
+        self.applied
+    }
+
+    /// Applies points until the first `n` are on the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `n` points exist, if more than `n` have been applied already, or
+    /// if one of the points lies outside of the grid.
+    pub fn apply(&mut self, n: usize) {
+        assert!(
+            n >= self.applied,
+            "cannot un-apply points: {} applied, {n} requested",
+            self.applied
+        );
+        assert!(
+            n <= self.points.len(),
+            "cannot apply {n} points, the stream only has {}",
+            self.points.len()
+        );
+
+        for &point in &self.points[self.applied..n] {
+            self.grid.set(point, self.mark);
+        }
+        self.applied = n;
+    }
+
+    /// Applies the next point and returns it, or `None` when all points are applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the next point lies outside of the grid.
+    pub fn push(&mut self) -> Option<Point> {
+        let point = *self.points.get(self.applied)?;
+        self.grid.set(point, self.mark);
+        self.applied += 1;
+        Some(point)
+    }
+
+    /// Returns a fresh grid with exactly the first `n` points applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `n` points exist, or if one of the first `n` lies outside of
+    /// the grid.
+    pub fn grid_at(&self, n: usize) -> Grid<T> {
+        Grid::from_points(
+            self.points[..n].iter().copied(),
+            self.grid.width,
+            self.grid.height,
+            self.fill,
+            self.mark,
+        )
+    }
+
+    /// Returns the first point whose addition makes `blocked` true, using a binary search
+    /// over the number of applied points. `blocked` must stay true once it becomes true.
+    ///
+    /// Returns `None` if no point causes a block: either `blocked` is still false with every
+    /// point applied, or it is already true before any point is.
+    pub fn first_blocking<F>(&self, mut blocked: F) -> Option<Point>
+    where
+        F: FnMut(&Grid<T>) -> bool,
+    {
+        let (mut low, mut high) = (0, self.points.len());
+        if blocked(&self.grid_at(low)) || !blocked(&self.grid_at(high)) {
+            return None;
+        }
+
+        // Invariant: `low` points do not block, `high` points do.
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            if blocked(&self.grid_at(middle)) {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+
+        Some(self.points[high - 1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coordinates: &[(isize, isize)]) -> Vec<Point> {
+        coordinates.iter().map(|&(x, y)| Point { x, y }).collect()
+    }
+
+    #[test]
+    pub fn test_from_points() {
+        let grid = Grid::from_points(points(&[(0, 0), (2, 1)]), 3, 2, '.', '#');
+        assert_eq!(
+            grid,
+            Grid::new(vec![vec!['#', '.', '.'], vec!['.', '.', '#']])
+        );
+    }
+
+    #[test]
+    pub fn test_builder_infers_bounds() {
+        let grid = Grid::builder(0)
+            .mark(points(&[(1, 0), (3, 2)]), 1)
+            .mark(points(&[(3, 2)]), 2)
+            .build();
+        assert_eq!(grid.width, 4);
+        assert_eq!(grid.height, 3);
+        assert_eq!(grid.get(Point { x: 1, y: 0 }), 1);
+        assert_eq!(grid.get(Point { x: 3, y: 2 }), 2);
+        assert_eq!(grid.count(0), 10);
+
+        let grid = Grid::builder(false)
+            .width(7)
+            .mark(points(&[(1, 1)]), true)
+            .build();
+        assert_eq!(grid.width, 7);
+        assert_eq!(grid.height, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot build a grid with the negative point (2, -1)")]
+    pub fn test_builder_rejects_negative_points() {
+        Grid::builder(false)
+            .mark(points(&[(1, 1), (2, -1)]), true)
+            .build();
+    }
+
+    #[test]
+    pub fn test_builder_without_marks() {
+        let grid = Grid::builder('.').build();
+        assert_eq!(grid, Grid::new(vec![vec!['.']]));
+    }
+
+    #[test]
+    #[should_panic(expected = "grid input is empty")]
+    pub fn test_builder_rejects_empty_size() {
+        Grid::builder('.').width(0).build();
+    }
+
+    #[test]
+    pub fn test_point_stream() {
+        let mut stream = PointStream::new(points(&[(0, 0), (1, 1), (2, 2)]), 3, 3, false, true);
+        assert_eq!(stream.grid().count(true), 0);
+
+        stream.apply(2);
+        assert_eq!(stream.applied(), 2);
+        assert_eq!(stream.grid().count(true), 2);
+        assert_eq!(stream.grid(), &stream.grid_at(2));
+
+        assert_eq!(stream.push(), Some(Point { x: 2, y: 2 }));
+        assert_eq!(stream.push(), None);
+        assert_eq!(stream.grid().count(true), 3);
+    }
+
+    #[test]
+    pub fn test_first_blocking() {
+        // The second column is walled off top to bottom by the fifth point.
+        let stream = PointStream::new(
+            points(&[(0, 0), (1, 0), (2, 2), (1, 1), (1, 2), (0, 2)]),
+            3,
+            3,
+            false,
+            true,
+        );
+        let column_blocked = |grid: &Grid<bool>| grid.col(1).all(|wall| wall);
+        assert_eq!(
+            stream.first_blocking(column_blocked),
+            Some(Point { x: 1, y: 2 })
+        );
+        assert_eq!(stream.first_blocking(|grid| grid.count(true) > 10), None);
+        assert_eq!(stream.first_blocking(|_| true), None);
+
+        let empty = PointStream::new(Vec::new(), 2, 2, false, true);
+        assert_eq!(empty.first_blocking(|grid| grid.count(false) == 4), None);
+    }
+}
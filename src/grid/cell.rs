@@ -0,0 +1,182 @@
+use crate::{Grid, GridError, Point};
+use std::fmt::{Debug, Display, Formatter};
+
+/// A value that is written as a single character in puzzle input.
+pub trait Cell: Sized {
+    /// Returns `None` if the character does not describe a valid cell.
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// `#` is `true`, `.` is `false`.
+impl Cell for bool {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// Declares a `Copy` enum that implements [`Cell`](crate::Cell) from a table of characters
+/// and variants. The first variant is the [`Default`].
+///
+/// ```ignore
+/// advent_of_code::cell_enum! {
+///     pub enum Tile {
+///         '.' => Open,
+///         '#' => Wall,
+///         '^' => Guard,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! cell_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $first_char:literal => $first:ident
+            $(, $char:literal => $variant:ident)* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+        $vis enum $name {
+            #[default]
+            $first,
+            $($variant,)*
+        }
+
+        impl $crate::Cell for $name {
+            fn from_char(c: char) -> Option<Self> {
+                match c {
+                    $first_char => Some($name::$first),
+                    $($char => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+
+            fn to_char(&self) -> char {
+                match self {
+                    $name::$first => $first_char,
+                    $($name::$variant => $char,)*
+                }
+            }
+        }
+    };
+}
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq + Debug + Default + Cell,
+{
+    /// Parses a grid with one row per line and one cell per character.
+    pub fn parse(input: &str) -> Result<Grid<T>, GridError> {
+        let cells = input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        T::from_char(c).ok_or(GridError::InvalidCell {
+                            point: Point {
+                                x: x as isize,
+                                y: y as isize,
+                            },
+                            found: c,
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Grid::try_new(cells)
+    }
+}
+
+/// Renders one line per row, without a trailing newline.
+impl<T> Display for Grid<T>
+where
+    T: Cell,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.cells.iter().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::{read_file, Day};
+
+    cell_enum! {
+        enum Tile {
+            '.' => Open,
+            '#' => Wall,
+            '^' => Guard,
+        }
+    }
+
+    #[test]
+    pub fn test_cell_enum() {
+        assert_eq!(Tile::from_char('#'), Some(Tile::Wall));
+        assert_eq!(Tile::from_char('x'), None);
+        assert_eq!(Tile::Guard.to_char(), '^');
+        assert_eq!(Tile::default(), Tile::Open);
+    }
+
+    #[test]
+    pub fn test_parse() {
+        let grid = Grid::<Tile>::parse(".#.\n.^#").unwrap();
+        assert_eq!(grid.width, 3);
+        assert_eq!(grid.height, 2);
+        assert_eq!(grid.find(Tile::Guard), Some(Point { x: 1, y: 1 }));
+
+        let grid = Grid::<bool>::parse("#.\n.#").unwrap();
+        assert_eq!(grid.count(true), 2);
+
+        assert_eq!(
+            Grid::<Tile>::parse("..\n.X"),
+            Err(GridError::InvalidCell {
+                point: Point { x: 1, y: 1 },
+                found: 'X'
+            })
+        );
+        assert_eq!(Grid::<char>::parse(""), Err(GridError::Empty));
+    }
+
+    #[test]
+    pub fn test_round_trip_example() {
+        let input = read_file("examples", Day::new(6).unwrap());
+        let grid = Grid::<Tile>::parse(&input).unwrap();
+        assert_eq!(grid.to_string(), input);
+    }
+}
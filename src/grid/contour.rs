@@ -0,0 +1,123 @@
+use crate::{Direction, Grid, Point};
+use std::fmt::Debug;
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Returns the outer boundary of the cluster containing `point` as a polygon.
+    ///
+    /// Cell `(x, y)` is treated as the unit square between the corners `(x, y)` and
+    /// `(x + 1, y + 1)`, and the returned vertices are the corners where the boundary
+    /// turns. They run clockwise on screen, starting at the top left corner of the
+    /// cluster. Holes are not traced.
+    pub fn contour(&self, point: Point) -> Vec<Point> {
+        let mut region = Grid::<bool>::new_empty(self.width, self.height);
+        for point in self.get_cluster(point) {
+            region.set(point, true);
+        }
+        let inside = |x: isize, y: isize| region.get_safe(Point { x, y }).unwrap_or(false);
+
+        // The top left corner of the first cell in reading order always lies on the
+        // outer boundary, with an edge leading east.
+        let start = region.find(true).unwrap();
+        let mut corner = start;
+        let mut heading = Direction::East;
+        let mut vertices = vec![start];
+
+        loop {
+            corner = corner.translate_direction(heading);
+            if corner == start {
+                break;
+            }
+
+            let (x, y) = (corner.x, corner.y);
+            // An edge leaving the corner is part of the boundary when the cell to its right
+            // is inside the cluster and the cell to its left is not.
+            let follows_boundary = |heading: Direction| match heading {
+                Direction::North => inside(x, y - 1) && !inside(x - 1, y - 1),
+                Direction::East => inside(x, y) && !inside(x, y - 1),
+                Direction::South => inside(x - 1, y) && !inside(x, y),
+                Direction::West => inside(x - 1, y - 1) && !inside(x - 1, y),
+                _ => unreachable!(),
+            };
+
+            // Preferring right turns keeps diagonally touching cells apart.
+            let next = [heading.turn_right(), heading, heading.turn_left()]
+                .into_iter()
+                .find(|&h| follows_boundary(h))
+                .unwrap();
+
+            if next != heading {
+                vertices.push(corner);
+                heading = next;
+            }
+        }
+
+        vertices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Polygon;
+
+    #[test]
+    pub fn test_contour_single_cell() {
+        let grid = Grid::<char>::parse("...\n.A.\n...").unwrap();
+        assert_eq!(
+            grid.contour(Point { x: 1, y: 1 }),
+            vec![
+                Point { x: 1, y: 1 },
+                Point { x: 2, y: 1 },
+                Point { x: 2, y: 2 },
+                Point { x: 1, y: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_contour_concave() {
+        let grid = Grid::<char>::parse("AAA\nA..\nAAA").unwrap();
+        let contour = grid.contour(Point { x: 2, y: 2 });
+        assert_eq!(
+            contour,
+            vec![
+                Point { x: 0, y: 0 },
+                Point { x: 3, y: 0 },
+                Point { x: 3, y: 1 },
+                Point { x: 1, y: 1 },
+                Point { x: 1, y: 2 },
+                Point { x: 3, y: 2 },
+                Point { x: 3, y: 3 },
+                Point { x: 0, y: 3 },
+            ]
+        );
+
+        let polygon = Polygon::new(contour);
+        assert_eq!(polygon.signed_double_area(), Some(14));
+        assert_eq!(polygon.boundary_length(), Some(16));
+    }
+
+    #[test]
+    pub fn test_contour_ignores_holes() {
+        let grid = Grid::<char>::parse("AAA\nA.A\nAAA").unwrap();
+        let polygon = Polygon::new(grid.contour(Point { x: 0, y: 0 }));
+        assert_eq!(polygon.vertices.len(), 4);
+        assert_eq!(polygon.double_area(), Some(18));
+    }
+
+    #[test]
+    pub fn test_contour_diagonal_touch() {
+        // The B cells only touch diagonally, so they are not part of the same cluster.
+        let grid = Grid::<char>::parse("AB\nBA").unwrap();
+        assert_eq!(grid.contour(Point { x: 1, y: 0 }).len(), 4);
+
+        // Here the A cluster touches itself diagonally in the middle; the boundary passes
+        // through that corner twice instead of cutting across.
+        let grid = Grid::<char>::parse("AAA\nA.A\nAA.\n.AA").unwrap();
+        let polygon = Polygon::new(grid.contour(Point { x: 0, y: 0 }));
+        assert_eq!(polygon.double_area(), Some(2 * 9));
+    }
+}
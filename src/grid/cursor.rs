@@ -0,0 +1,232 @@
+use crate::{Direction, Grid, Point};
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+/// Why [`GridCursor::step_until_blocked`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CursorStop {
+    /// The cell ahead is blocked.
+    Blocked,
+    /// The cell ahead is outside the grid.
+    Edge,
+    /// The cursor is in a position and heading it has been in before, so it is walking in
+    /// circles. Only reported with revisit detection enabled.
+    Revisit,
+}
+
+/// A position and heading that walks over a grid, like a guard or a robot.
+///
+/// The cursor does not borrow the grid; the methods that need to look at cells take it as an
+/// argument, so the grid can change between steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridCursor {
+    position: Point,
+    heading: Direction,
+    trail: Option<Vec<Point>>,
+    seen: Option<HashSet<(Point, Direction)>>,
+    revisited: bool,
+}
+
+impl GridCursor {
+    pub fn new(position: Point, heading: Direction) -> Self {
+        Self {
+            position,
+            heading,
+            trail: None,
+            seen: None,
+            revisited: false,
+        }
+    }
+
+    /// Records every position the cursor occupies, starting with the current one.
+    pub fn with_trail(mut self) -> Self {
+        self.trail = Some(vec![self.position]);
+        self
+    }
+
+    /// Remembers every position and heading the cursor has been in, starting with the current
+    /// one, so that walking in circles can be detected.
+    pub fn with_revisit_detection(mut self) -> Self {
+        self.seen = Some(HashSet::from([(self.position, self.heading)]));
+        self
+    }
+
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    pub fn heading(&self) -> Direction {
+        self.heading
+    }
+
+    /// The point one step ahead.
+    pub fn ahead(&self) -> Point {
+        self.position.translate_direction(self.heading)
+    }
+
+    /// The positions occupied so far, in order and including repeats. Empty unless the
+    /// cursor was created [`with_trail`](GridCursor::with_trail).
+    pub fn trail(&self) -> &[Point] {
+        self.trail.as_deref().unwrap_or_default()
+    }
+
+    /// Whether the cursor has repeated a position and heading. Always false unless the
+    /// cursor was created [`with_revisit_detection`](GridCursor::with_revisit_detection).
+    pub fn has_revisited(&self) -> bool {
+        self.revisited
+    }
+
+    pub fn turn_right(&mut self) {
+        self.heading = self.heading.turn_right();
+        self.record();
+    }
+
+    pub fn turn_left(&mut self) {
+        self.heading = self.heading.turn_left();
+        self.record();
+    }
+
+    /// Returns the value of the cell ahead, or `None` if it is outside the grid.
+    pub fn peek<T>(&self, grid: &Grid<T>) -> Option<T>
+    where
+        T: Copy + PartialEq + Debug + Default,
+    {
+        let ahead = self.ahead();
+        (!grid.out_of_bounds(&ahead)).then(|| grid.get(ahead))
+    }
+
+    /// Moves one step ahead, whatever is there. Returns false if this repeats an earlier
+    /// position and heading.
+    pub fn step(&mut self) -> bool {
+        self.position = self.ahead();
+        if let Some(trail) = &mut self.trail {
+            trail.push(self.position);
+        }
+        self.record()
+    }
+
+    /// Steps ahead until the next cell is blocked or outside the grid, or until the cursor
+    /// starts walking in circles.
+    pub fn step_until_blocked<T, F>(&mut self, grid: &Grid<T>, mut is_blocked: F) -> CursorStop
+    where
+        T: Copy + PartialEq + Debug + Default,
+        F: FnMut(&T) -> bool,
+    {
+        loop {
+            if self.revisited {
+                return CursorStop::Revisit;
+            }
+            match self.peek(grid) {
+                None => return CursorStop::Edge,
+                Some(value) if is_blocked(&value) => return CursorStop::Blocked,
+                Some(_) => {
+                    self.step();
+                }
+            }
+        }
+    }
+
+    /// Remembers the current position and heading, returning false if they were seen before.
+    fn record(&mut self) -> bool {
+        let Some(seen) = &mut self.seen else {
+            return true;
+        };
+        let new = seen.insert((self.position, self.heading));
+        self.revisited |= !new;
+        new
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOM: &str = "\
+.#...
+....#
+#....
+...#.";
+
+    fn walls() -> Grid<bool> {
+        Grid::parse(ROOM).unwrap()
+    }
+
+    #[test]
+    pub fn test_cursor_peek_and_step() {
+        let grid = walls();
+        let mut cursor = GridCursor::new(Point { x: 0, y: 0 }, Direction::East);
+        assert_eq!(cursor.peek(&grid), Some(true));
+        cursor.turn_left();
+        assert_eq!(cursor.heading(), Direction::North);
+        assert_eq!(cursor.peek(&grid), None);
+
+        cursor.turn_left();
+        cursor.turn_left();
+        assert!(cursor.step());
+        assert_eq!(cursor.position(), Point { x: 0, y: 1 });
+        assert!(cursor.trail().is_empty());
+        assert!(!cursor.has_revisited());
+    }
+
+    #[test]
+    pub fn test_cursor_turns() {
+        let mut cursor = GridCursor::new(Point { x: 0, y: 0 }, Direction::West);
+        cursor.turn_right();
+        assert_eq!(cursor.heading(), Direction::North);
+
+        let mut cursor = GridCursor::new(Point { x: 0, y: 0 }, Direction::SouthEast);
+        cursor.turn_right();
+        assert_eq!(cursor.heading(), Direction::SouthWest);
+        cursor.turn_left();
+        cursor.turn_left();
+        assert_eq!(cursor.heading(), Direction::NorthEast);
+    }
+
+    #[test]
+    pub fn test_cursor_step_until_blocked() {
+        let grid = walls();
+        let mut cursor = GridCursor::new(Point { x: 0, y: 1 }, Direction::East).with_trail();
+
+        assert_eq!(
+            cursor.step_until_blocked(&grid, |&wall| wall),
+            CursorStop::Blocked
+        );
+        assert_eq!(cursor.position(), Point { x: 3, y: 1 });
+        cursor.turn_left();
+        assert_eq!(
+            cursor.step_until_blocked(&grid, |&wall| wall),
+            CursorStop::Edge
+        );
+        assert_eq!(
+            cursor.trail(),
+            [
+                Point { x: 0, y: 1 },
+                Point { x: 1, y: 1 },
+                Point { x: 2, y: 1 },
+                Point { x: 3, y: 1 },
+                Point { x: 3, y: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_cursor_revisit_detection() {
+        let grid = walls();
+        let mut cursor =
+            GridCursor::new(Point { x: 1, y: 1 }, Direction::North).with_revisit_detection();
+
+        // North, east, south and west are all blocked after a few steps, so turning right
+        // at every wall walks in circles.
+        let mut turns = 0;
+        let stop = loop {
+            match cursor.step_until_blocked(&grid, |&wall| wall) {
+                CursorStop::Blocked => cursor.turn_right(),
+                stop => break stop,
+            }
+            turns += 1;
+        };
+        assert_eq!(stop, CursorStop::Revisit);
+        assert_eq!(turns, 4);
+        assert!(cursor.has_revisited());
+    }
+}
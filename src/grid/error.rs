@@ -0,0 +1,68 @@
+use crate::Point;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// The input has no rows, or its rows have no cells.
+    Empty,
+    /// A row's length differs from the length of the first row.
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A point lies outside of the grid.
+    OutOfBounds {
+        point: Point,
+        width: isize,
+        height: isize,
+    },
+    /// A character does not describe a valid cell.
+    InvalidCell { point: Point, found: char },
+    /// A layer's size differs from the size of the first layer.
+    LayerMismatch {
+        layer: &'static str,
+        expected: (isize, isize),
+        found: (isize, isize),
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid input is empty"),
+            GridError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {row} has {found} cells, expected {expected} like the first row"
+            ),
+            GridError::OutOfBounds {
+                point,
+                width,
+                height,
+            } => write!(
+                f,
+                "point ({}, {}) is out of bounds for a {width}x{height} grid",
+                point.x, point.y
+            ),
+            GridError::InvalidCell { point, found } => {
+                write!(f, "invalid cell {found:?} at ({}, {})", point.x, point.y)
+            }
+            GridError::LayerMismatch {
+                layer,
+                expected,
+                found,
+            } => write!(
+                f,
+                "layer {layer} is {}x{}, expected {}x{} like the first layer",
+                found.0, found.1, expected.0, expected.1
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
@@ -1,16 +1,28 @@
 use crate::{Direction, Grid, Point};
 use std::fmt::Debug;
+use std::iter::FusedIterator;
 
 // ------------------------------------------------------------------------------------------------
 // ------------------------------------------------------------------------------------------------
 // GridIterator
 // ------------------------------------------------------------------------------------------------
 // ------------------------------------------------------------------------------------------------
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+/// Iterates over the values of a grid, either in row-major order or along a line.
+///
+/// Every visited point is addressed by its index along the walk, which makes the iterator
+/// double ended and lets it skip ahead in constant time.
+#[derive(Debug, Clone, PartialEq)]
 pub struct GridIterator<'a, T> {
     grid: &'a Grid<T>,
-    current: Option<Point>,
-    direction: Option<Direction>,
+    walk: Walk,
+    front: usize,
+    back: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Walk {
+    RowMajor,
+    Line { start: Point, dx: isize, dy: isize },
 }
 
 impl<'a, T> GridIterator<'a, T>
@@ -20,78 +32,396 @@ where
     pub fn new(grid: &'a Grid<T>) -> Self {
         Self {
             grid,
-            current: Some(Point { x: 0, y: 0 }),
-            direction: None,
+            walk: Walk::RowMajor,
+            front: 0,
+            back: (grid.width * grid.height) as usize,
         }
     }
 
-    pub fn calculate_next_point(&mut self) {
-        let new = self.current.take().unwrap();
+    /// Walks from `point` in `direction` until the edge of the grid.
+    pub fn in_direction(self, direction: Direction, point: Point) -> Self {
+        let (dx, dy) = direction.delta();
+        self.in_steps(point, dx, dy)
+    }
+
+    /// Walks from `point` in steps of `(dx, dy)` until the edge of the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is `(0, 0)`, which would never leave the grid.
+    pub fn in_steps(self, point: Point, dx: isize, dy: isize) -> Self {
+        assert!(dx != 0 || dy != 0, "cannot walk in steps of (0, 0)");
+        let walk = Walk::Line {
+            start: point,
+            dx,
+            dy,
+        };
 
-        if self.direction.is_none() {
-            self.simple_walk(new);
-        } else {
-            self.complex_direction(new);
+        Self {
+            grid: self.grid,
+            walk,
+            front: 0,
+            back: self.steps_in_bounds(point, dx, dy),
         }
     }
 
-    fn simple_walk(&mut self, mut new: Point) {
-        new.x += 1;
-        if new.x >= self.grid.width {
-            new.x = 0;
-            new.y += 1;
+    /// Yields the point of every value along with it.
+    pub fn with_points(self) -> WithPoints<'a, T> {
+        WithPoints { iter: self }
+    }
+
+    /// Yields only the points that would be visited.
+    pub fn points(self) -> Points<'a, T> {
+        Points { iter: self }
+    }
+
+    /// Stops before the first value matching `predicate`, see [`TakeUntil`].
+    pub fn take_until<P>(self, predicate: P) -> TakeUntil<Self, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        TakeUntil::new(self, predicate)
+    }
+
+    /// The number of points from `start` onwards that lie inside the grid.
+    fn steps_in_bounds(&self, start: Point, dx: isize, dy: isize) -> usize {
+        if self.grid.out_of_bounds(&start) {
+            return 0;
         }
-        if new.y >= self.grid.height {
-            self.current = None;
-        } else {
-            self.current = Some(new);
+
+        let axis = |position: isize, delta: isize, size: isize| match delta {
+            0 => usize::MAX,
+            d if d > 0 => ((size - 1 - position) / d + 1) as usize,
+            d => (position / -d + 1) as usize,
+        };
+        axis(start.x, dx, self.grid.width).min(axis(start.y, dy, self.grid.height))
+    }
+
+    fn point_at(&self, index: usize) -> Point {
+        match self.walk {
+            Walk::RowMajor => {
+                let index = index as isize;
+                Point {
+                    x: index % self.grid.width,
+                    y: index / self.grid.width,
+                }
+            }
+            Walk::Line { start, dx, dy } => {
+                let index = index as isize;
+                start.offset(dx * index, dy * index)
+            }
         }
     }
 
-    fn complex_direction(&mut self, mut new: Point) {
-        let (dx, dy) = self.direction.as_ref().unwrap().delta();
-        new.x += dx;
-        new.y += dy;
+    fn front_point(&self) -> Option<Point> {
+        (self.front < self.back).then(|| self.point_at(self.front))
+    }
+
+    fn value_at(&self, point: Point) -> T {
+        self.grid.cells[point.y as usize][point.x as usize]
+    }
+
+    fn next_point(&mut self) -> Option<Point> {
+        let point = self.front_point()?;
+        self.front += 1;
+        Some(point)
+    }
 
-        if self.grid.out_of_bounds(&new) {
-            self.current = None;
-        } else {
-            self.current = Some(new);
+    fn next_back_point(&mut self) -> Option<Point> {
+        if self.front >= self.back {
+            return None;
         }
+        self.back -= 1;
+        Some(self.point_at(self.back))
+    }
+
+    fn nth_point(&mut self, n: usize) -> Option<Point> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next_point()
+    }
+}
+
+impl<T> Iterator for GridIterator<'_, T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_point().map(|point| self.value_at(point))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
     }
 
-    pub fn with_points(mut self) -> impl Iterator<Item = (Point, T)> + 'a
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.nth_point(n).map(|point| self.value_at(point))
+    }
+}
+
+impl<T> DoubleEndedIterator for GridIterator<'_, T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_back_point().map(|point| self.value_at(point))
+    }
+}
+
+impl<T> ExactSizeIterator for GridIterator<'_, T> where T: Copy + PartialEq + Debug + Default {}
+
+impl<T> FusedIterator for GridIterator<'_, T> where T: Copy + PartialEq + Debug + Default {}
+
+/// Iterates over `(point, value)` pairs, see [`GridIterator::with_points`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithPoints<'a, T> {
+    iter: GridIterator<'a, T>,
+}
+
+impl<'a, T> WithPoints<'a, T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Stops before the first pair matching `predicate`, see [`TakeUntil`].
+    pub fn take_until<P>(self, predicate: P) -> TakeUntil<Self, P>
     where
-        T: PartialEq + Copy + Debug + Default,
+        P: FnMut(&(Point, T)) -> bool,
     {
-        std::iter::from_fn(move || {
-            let point = self.current;
+        TakeUntil::new(self, predicate)
+    }
+}
 
-            self.next().map(|value| (point.unwrap(), value))
-        })
+impl<T> Iterator for WithPoints<'_, T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    type Item = (Point, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.iter.next_point()?;
+        Some((point, self.iter.value_at(point)))
     }
 
-    pub fn in_direction(mut self, direction: Direction, point: Point) -> Self {
-        self.direction = Some(direction);
-        self.current = Some(point);
-        self
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let point = self.iter.nth_point(n)?;
+        Some((point, self.iter.value_at(point)))
     }
 }
 
-impl<T> Iterator for GridIterator<'_, T>
+impl<T> DoubleEndedIterator for WithPoints<'_, T>
 where
     T: Copy + PartialEq + Debug + Default,
 {
-    type Item = T;
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let point = self.iter.next_back_point()?;
+        Some((point, self.iter.value_at(point)))
+    }
+}
+
+impl<T> ExactSizeIterator for WithPoints<'_, T> where T: Copy + PartialEq + Debug + Default {}
+
+impl<T> FusedIterator for WithPoints<'_, T> where T: Copy + PartialEq + Debug + Default {}
+
+/// Iterates over points only, see [`GridIterator::points`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Points<'a, T> {
+    iter: GridIterator<'a, T>,
+}
+
+impl<T> Iterator for Points<'_, T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    type Item = Point;
+
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(current) = self.current {
-            let value = self.grid.get(current);
-            self.calculate_next_point();
-            Some(value)
-        } else {
-            None
+        self.iter.next_point()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_point(n)
+    }
+}
+
+impl<T> DoubleEndedIterator for Points<'_, T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back_point()
+    }
+}
+
+impl<T> ExactSizeIterator for Points<'_, T> where T: Copy + PartialEq + Debug + Default {}
+
+impl<T> FusedIterator for Points<'_, T> where T: Copy + PartialEq + Debug + Default {}
+
+/// Why a [`TakeUntil`] iterator ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StopReason {
+    /// The walk left the grid.
+    Edge,
+    /// The next item matched the predicate.
+    Predicate,
+    /// The step limit set with [`TakeUntil::max_steps`] was reached.
+    StepLimit,
+}
+
+/// Yields items until one matches a predicate, the underlying walk ends, or an optional step
+/// limit is reached. The matching item itself is not yielded.
+///
+/// Once the iterator is exhausted, [`TakeUntil::stop_reason`] tells which of these happened.
+#[derive(Debug, Clone)]
+pub struct TakeUntil<I, P> {
+    iter: I,
+    predicate: P,
+    limit: Option<usize>,
+    taken: usize,
+    stopped: Option<StopReason>,
+}
+
+impl<I, P> TakeUntil<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    fn new(iter: I, predicate: P) -> Self {
+        Self {
+            iter,
+            predicate,
+            limit: None,
+            taken: 0,
+            stopped: None,
         }
     }
+
+    /// Ends the iterator after at most `steps` items.
+    pub fn max_steps(mut self, steps: usize) -> Self {
+        self.limit = Some(steps);
+        self
+    }
+
+    /// Returns why the iterator ended, or `None` while it has not ended yet.
+    pub fn stop_reason(&self) -> Option<StopReason> {
+        self.stopped
+    }
+}
+
+impl<I, P> Iterator for TakeUntil<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stopped.is_some() {
+            return None;
+        }
+        if self.limit.is_some_and(|limit| self.taken >= limit) {
+            self.stopped = Some(StopReason::StepLimit);
+            return None;
+        }
+
+        match self.iter.next() {
+            None => {
+                self.stopped = Some(StopReason::Edge);
+                None
+            }
+            Some(item) if (self.predicate)(&item) => {
+                self.stopped = Some(StopReason::Predicate);
+                None
+            }
+            Some(item) => {
+                self.taken += 1;
+                Some(item)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.stopped.is_some() {
+            return (0, Some(0));
+        }
+        let (_, upper) = self.iter.size_hint();
+        let remaining = self.limit.map(|limit| limit - self.taken);
+        let upper = match (upper, remaining) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        (0, upper)
+    }
+}
+
+impl<I, P> FusedIterator for TakeUntil<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+}
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Returns the values of row `y`, from west to east.
+    pub fn row(&self, y: isize) -> GridIterator<'_, T> {
+        self.iter().in_direction(Direction::East, Point { x: 0, y })
+    }
+
+    /// Returns the values of column `x`, from north to south.
+    pub fn col(&self, x: isize) -> GridIterator<'_, T> {
+        self.iter()
+            .in_direction(Direction::South, Point { x, y: 0 })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = GridIterator<'_, T>> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = GridIterator<'_, T>> + '_ {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    /// Returns every maximal line running in `direction`, each with the point it starts at.
+    /// Together the lines cover every cell exactly once; they are ordered by their starting
+    /// points in reading order.
+    ///
+    /// East and west give the rows, north and south the columns, and the diagonal directions
+    /// the diagonals or anti-diagonals.
+    pub fn lines(
+        &self,
+        direction: Direction,
+    ) -> impl Iterator<Item = (Point, GridIterator<'_, T>)> + '_ {
+        let (dx, dy) = direction.delta();
+        self.iter()
+            .points()
+            .filter(move |point| self.out_of_bounds(&point.offset(-dx, -dy)))
+            .map(move |start| (start, self.iter().in_direction(direction, start)))
+    }
+
+    /// Returns every cell in row-major order, allowing it to be modified in place.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> + '_ {
+        self.cells.iter_mut().enumerate().flat_map(|(y, row)| {
+            row.iter_mut().enumerate().map(move |(x, value)| {
+                let point = Point {
+                    x: x as isize,
+                    y: y as isize,
+                };
+                (point, value)
+            })
+        })
+    }
 }
 
 #[cfg(test)]
@@ -109,10 +439,10 @@ mod tests {
     pub fn test_new_grid_iterator() {
         let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
         let mut iter = grid.iter();
-        assert_eq!(iter.current, Some(Point { x: 0, y: 0 }));
+        assert_eq!(iter.front_point(), Some(Point { x: 0, y: 0 }));
 
         assert_eq!(iter.next(), Some(1));
-        assert_eq!(iter.current, Some(Point { x: 1, y: 0 }));
+        assert_eq!(iter.front_point(), Some(Point { x: 1, y: 0 }));
 
         assert_eq!(iter.next(), Some(2));
         assert_eq!(iter.next(), Some(3));
@@ -139,6 +469,34 @@ mod tests {
         assert_eq!(iter.next(), Some((Point { x: 2, y: 2 }, 9)));
     }
 
+    #[test]
+    pub fn test_rows_and_cols() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(grid.row(1).collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(grid.col(2).collect::<Vec<_>>(), vec![3, 6]);
+
+        let rows: Vec<Vec<_>> = grid.rows().map(|row| row.collect()).collect();
+        assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let cols: Vec<Vec<_>> = grid.cols().map(|col| col.collect()).collect();
+        assert_eq!(cols, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+
+        let mut col = grid.col(1).with_points();
+        assert_eq!(col.next(), Some((Point { x: 1, y: 0 }, 2)));
+        assert_eq!(col.next(), Some((Point { x: 1, y: 1 }, 5)));
+        assert_eq!(col.next(), None);
+    }
+
+    #[test]
+    pub fn test_iter_mut() {
+        let mut grid = Grid::new(vec![vec![1, 2], vec![3, 4]]);
+        for (point, value) in grid.iter_mut() {
+            *value += point.x * 10 + point.y * 100;
+        }
+        assert_eq!(grid, Grid::new(vec![vec![1, 12], vec![103, 114]]));
+    }
+
     #[test]
     pub fn test_grid_iter_with_direction() {
         let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
@@ -146,10 +504,156 @@ mod tests {
             .iter()
             .in_direction(Direction::SouthEast, Point { x: 0, y: 0 });
         assert_eq!(iter.next(), Some(1));
-        assert_eq!(iter.current, Some(Point { x: 1, y: 1 }));
+        assert_eq!(iter.front_point(), Some(Point { x: 1, y: 1 }));
         assert_eq!(iter.next(), Some(5));
-        assert_eq!(iter.current, Some(Point { x: 2, y: 2 }));
+        assert_eq!(iter.front_point(), Some(Point { x: 2, y: 2 }));
         assert_eq!(iter.next(), Some(9));
-        assert_eq!(iter.current, None);
+        assert_eq!(iter.front_point(), None);
+    }
+
+    #[test]
+    pub fn test_grid_iterator_exact_size() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let mut iter = grid.iter();
+        assert_eq!(iter.len(), 9);
+        iter.next();
+        assert_eq!(iter.len(), 8);
+        assert_eq!(grid.iter().with_points().len(), 9);
+
+        let diagonal = grid
+            .iter()
+            .in_direction(Direction::NorthWest, Point { x: 2, y: 1 });
+        assert_eq!(diagonal.len(), 2);
+        assert_eq!(diagonal.collect::<Vec<_>>(), vec![6, 2]);
+
+        let outside = grid
+            .iter()
+            .in_direction(Direction::East, Point { x: 3, y: 0 });
+        assert_eq!(outside.len(), 0);
+    }
+
+    #[test]
+    pub fn test_grid_iterator_double_ended() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let reversed: Vec<_> = grid.iter().rev().collect();
+        assert_eq!(reversed, vec![9, 8, 7, 6, 5, 4, 3, 2, 1]);
+
+        let mut iter = grid.iter().with_points();
+        assert_eq!(iter.next_back(), Some((Point { x: 2, y: 2 }, 9)));
+        assert_eq!(iter.next(), Some((Point { x: 0, y: 0 }, 1)));
+        assert_eq!(iter.len(), 7);
+
+        let column: Vec<_> = grid
+            .iter()
+            .in_direction(Direction::South, Point { x: 1, y: 0 })
+            .points()
+            .rev()
+            .collect();
+        assert_eq!(
+            column,
+            vec![Point { x: 1, y: 2 }, Point { x: 1, y: 1 }, Point { x: 1, y: 0 }]
+        );
+    }
+
+    #[test]
+    pub fn test_grid_iterator_nth() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let mut iter = grid.iter();
+        assert_eq!(iter.nth(4), Some(5));
+        assert_eq!(iter.next(), Some(6));
+        assert_eq!(iter.nth(10), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = grid.iter().with_points();
+        assert_eq!(iter.nth(7), Some((Point { x: 1, y: 2 }, 8)));
+        assert_eq!(iter.next_back(), Some((Point { x: 2, y: 2 }, 9)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    pub fn test_grid_iter_in_steps() {
+        let grid = Grid::from_fn(10, 4, |p| p.x + p.y * 10);
+        let points: Vec<_> = grid
+            .iter()
+            .in_steps(Point { x: 1, y: 0 }, 3, 1)
+            .points()
+            .collect();
+        assert_eq!(
+            points,
+            vec![Point { x: 1, y: 0 }, Point { x: 4, y: 1 }, Point { x: 7, y: 2 }]
+        );
+
+        let values: Vec<_> = grid.iter().in_steps(Point { x: 9, y: 3 }, -4, -2).collect();
+        assert_eq!(values, vec![39, 15]);
+        assert_eq!(grid.iter().in_steps(Point { x: 0, y: 0 }, 0, 2).len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot walk in steps of (0, 0)")]
+    pub fn test_grid_iter_in_steps_zero() {
+        let grid = Grid::new(vec![vec![1]]);
+        grid.iter().in_steps(Point { x: 0, y: 0 }, 0, 0);
+    }
+
+    #[test]
+    pub fn test_take_until() {
+        let grid = Grid::new(vec![vec!['.', '.', '#', '.'], vec!['.', '.', '.', '.']]);
+
+        let mut walk = grid
+            .iter()
+            .in_direction(Direction::East, Point { x: 0, y: 0 })
+            .with_points()
+            .take_until(|&(_, c)| c == '#');
+        assert_eq!(walk.stop_reason(), None);
+        assert_eq!(walk.by_ref().last(), Some((Point { x: 1, y: 0 }, '.')));
+        assert_eq!(walk.stop_reason(), Some(StopReason::Predicate));
+
+        let mut walk = grid.row(1).take_until(|&c| c == '#');
+        assert_eq!(walk.by_ref().count(), 4);
+        assert_eq!(walk.stop_reason(), Some(StopReason::Edge));
+        assert_eq!(walk.next(), None);
+
+        let mut walk = grid.row(1).take_until(|&c| c == '#').max_steps(3);
+        assert_eq!(walk.size_hint(), (0, Some(3)));
+        assert_eq!(walk.by_ref().count(), 3);
+        assert_eq!(walk.stop_reason(), Some(StopReason::StepLimit));
+    }
+
+    #[test]
+    pub fn test_lines() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let lines = |direction| -> Vec<(Point, Vec<_>)> {
+            grid.lines(direction)
+                .map(|(start, line)| (start, line.collect()))
+                .collect()
+        };
+
+        let west = lines(Direction::West);
+        assert_eq!(west[1], (Point { x: 2, y: 1 }, vec![6, 5, 4]));
+        assert_eq!(lines(Direction::South).len(), 3);
+
+        let diagonals: Vec<_> = lines(Direction::SouthEast)
+            .into_iter()
+            .map(|(_, line)| line)
+            .collect();
+        assert_eq!(diagonals, vec![vec![1, 5], vec![2, 6], vec![3], vec![4]]);
+
+        let anti_diagonals: Vec<_> = lines(Direction::NorthEast)
+            .into_iter()
+            .map(|(_, line)| line)
+            .collect();
+        assert_eq!(
+            anti_diagonals,
+            vec![vec![1], vec![4, 2], vec![5, 3], vec![6]]
+        );
+
+        // Every cell lies on exactly one line in each direction.
+        for direction in [Direction::East, Direction::SouthWest, Direction::NorthWest] {
+            assert_eq!(
+                lines(direction).iter().map(|(_, l)| l.len()).sum::<usize>(),
+                6
+            );
+        }
     }
 }
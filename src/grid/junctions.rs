@@ -0,0 +1,143 @@
+use crate::{Direction, Edge, Graph, Grid, Neighborhood, Point};
+use std::fmt::Debug;
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Compresses a maze into a graph whose nodes are its junctions (open cells with three or
+    /// more open neighbours) and the given points of interest. Corridors between two nodes
+    /// become edges carrying their length and cells, ending with the cell of the target node;
+    /// corridors ending in a dead end are dropped.
+    ///
+    /// `slope` marks one-way cells: a cell for which it returns a direction may only be left
+    /// in that direction. Pass `|_| None` if the maze has none.
+    pub fn junction_graph<O, S>(
+        &self,
+        points_of_interest: &[Point],
+        is_open: O,
+        slope: S,
+    ) -> Graph<Point, Vec<Point>>
+    where
+        O: Fn(T) -> bool,
+        S: Fn(T) -> Option<Direction>,
+    {
+        let open_neighbors = |point: Point| {
+            self.neighbors(point, Neighborhood::Cardinal)
+                .filter(|(_, _, &value)| is_open(value))
+        };
+        let can_leave = |point: Point, direction: Direction| {
+            slope(self.get(point)).is_none_or(|d| d == direction)
+        };
+
+        let mut graph = Graph::new();
+        for &point in points_of_interest {
+            graph.add_node(point);
+        }
+        for (point, value) in self.iter().with_points() {
+            if is_open(value) && open_neighbors(point).count() >= 3 {
+                graph.add_node(point);
+            }
+        }
+
+        let mut edges = Vec::new();
+        for (from, &start) in graph.nodes.iter().enumerate() {
+            'corridor: for (direction, next, _) in open_neighbors(start) {
+                if !can_leave(start, direction) {
+                    continue;
+                }
+
+                let (mut previous, mut current) = (start, next);
+                let mut path = vec![current];
+
+                while graph.node_index(&current).is_none() {
+                    let step = open_neighbors(current).find(|&(_, next, _)| next != previous);
+                    match step {
+                        Some((direction, next, _)) if can_leave(current, direction) => {
+                            (previous, current) = (current, next);
+                            path.push(current);
+                        }
+                        _ => continue 'corridor,
+                    }
+                }
+
+                let to = graph.node_index(&current).unwrap();
+                if to != from {
+                    let length = path.len();
+                    edges.push((from, Edge { to, length, path }));
+                }
+            }
+        }
+
+        for (from, edge) in edges {
+            graph.add_edge(from, edge);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+#S#####
+#...#.#
+#.#...#
+#...#.#
+###.#.#
+###...#
+#####E#";
+
+    fn parse(input: &str) -> Grid<char> {
+        Grid::parse(input).unwrap()
+    }
+
+    #[test]
+    pub fn test_junction_graph() {
+        let grid = parse(MAZE);
+        let start = Point { x: 1, y: 0 };
+        let end = Point { x: 5, y: 6 };
+        let graph = grid.junction_graph(&[start, end], |c| c != '#', |_| None);
+
+        // Start, end and the junctions at (1, 1), (3, 2), (5, 2), (3, 3) and (5, 5).
+        assert_eq!(graph.nodes.len(), 7);
+        assert_eq!(graph.nodes[..2], [start, end]);
+
+        let junction = graph.node_index(&Point { x: 1, y: 1 }).unwrap();
+        let edge = &graph.edges[graph.node_index(&start).unwrap()][0];
+        assert_eq!(edge.to, junction);
+        assert_eq!(edge.length, 1);
+        assert_eq!(edge.path, vec![Point { x: 1, y: 1 }]);
+
+        // Without slopes, every corridor can be walked both ways.
+        for (from, edges) in graph.edges.iter().enumerate() {
+            for edge in edges {
+                assert!(graph.edges[edge.to]
+                    .iter()
+                    .any(|back| back.to == from && back.length == edge.length));
+            }
+        }
+
+        assert_eq!(graph.shortest_path(&start, &end), Some(10));
+        assert_eq!(graph.longest_path(&start, &end), Some(12));
+    }
+
+    #[test]
+    pub fn test_junction_graph_slopes() {
+        // The slope at (4, 2) only allows walking east, from (3, 2) towards (5, 2).
+        let grid = parse(&MAZE.replace("#.#...#", "#.#.>.#"));
+        let start = Point { x: 1, y: 0 };
+        let end = Point { x: 5, y: 6 };
+        let slope = |c| match c {
+            '>' => Some(Direction::East),
+            _ => None,
+        };
+        let graph = grid.junction_graph(&[start, end], |c| c != '#', slope);
+
+        let from = graph.node_index(&Point { x: 5, y: 2 }).unwrap();
+        let to = graph.node_index(&Point { x: 3, y: 2 }).unwrap();
+        assert!(graph.edges[from].iter().all(|edge| edge.to != to));
+        assert!(graph.edges[to].iter().any(|edge| edge.to == from));
+    }
+}
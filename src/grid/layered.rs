@@ -0,0 +1,278 @@
+/// Declares a struct of equally sized grids, one per named layer, that share a coordinate
+/// space.
+///
+/// The layers are private, so that they always have the same size. Every layer gets a
+/// read-only accessor named after it, and names two more methods: `set` changes one of its
+/// cells, and `replace` swaps in a whole grid after checking its size.
+///
+/// Every layer also has a render function (or non-capturing closure) from a cell to an
+/// optional character. When rendering, the first layer in declaration order that returns a
+/// character wins, and the background character is used where no layer does.
+///
+/// ```ignore
+/// advent_of_code::layered_grid! {
+///     pub struct Lab {
+///         walls: bool {
+///             set: set_wall,
+///             replace: replace_walls,
+///             render: |&wall| wall.then_some('#'),
+///         },
+///         visited: bool {
+///             set: set_visited,
+///             replace: replace_visited,
+///             render: |&visited| visited.then_some('X'),
+///         },
+///     }
+/// }
+///
+/// let mut lab = Lab::new(10, 10);
+/// lab.set_visited(Point { x: 1, y: 1 }, true);
+/// let (wall, visited) = lab.get(Point { x: 1, y: 1 });
+/// ```
+#[macro_export]
+macro_rules! layered_grid {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($layer:ident : $cell:ty {
+                set: $set:ident,
+                replace: $replace:ident,
+                render: $render:expr $(,)?
+            }),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        $vis struct $name {
+            $($layer: $crate::Grid<$cell>,)+
+        }
+
+        impl $name {
+            /// Creates a layered grid with every layer filled with its default value.
+            #[allow(dead_code)]
+            pub fn new(width: isize, height: isize) -> Self {
+                Self {
+                    $($layer: $crate::Grid::new_empty(width, height),)+
+                }
+            }
+
+            /// Combines existing grids, which must all have the same size.
+            #[allow(dead_code)]
+            pub fn from_layers(
+                $($layer: $crate::Grid<$cell>,)+
+            ) -> Result<Self, $crate::GridError> {
+                let sizes = [$((stringify!($layer), ($layer.width, $layer.height)),)+];
+                let expected = sizes[0].1;
+                for (layer, found) in sizes {
+                    if found != expected {
+                        return Err($crate::GridError::LayerMismatch {
+                            layer,
+                            expected,
+                            found,
+                        });
+                    }
+                }
+
+                Ok(Self { $($layer,)+ })
+            }
+
+            $(
+                #[allow(dead_code)]
+                pub fn $layer(&self) -> &$crate::Grid<$cell> {
+                    &self.$layer
+                }
+
+                /// Sets the cell of the layer at `point`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `point` is outside of the grid.
+                #[allow(dead_code)]
+                pub fn $set(&mut self, point: $crate::Point, value: $cell) {
+                    self.$layer.set(point, value);
+                }
+
+                /// Replaces the layer with `grid` and returns the previous one, unless
+                /// `grid` has a different size.
+                #[allow(dead_code)]
+                pub fn $replace(
+                    &mut self,
+                    grid: $crate::Grid<$cell>,
+                ) -> Result<$crate::Grid<$cell>, $crate::GridError> {
+                    let expected = (self.width(), self.height());
+                    let found = (grid.width, grid.height);
+                    if found != expected {
+                        return Err($crate::GridError::LayerMismatch {
+                            layer: stringify!($layer),
+                            expected,
+                            found,
+                        });
+                    }
+                    Ok(std::mem::replace(&mut self.$layer, grid))
+                }
+            )+
+
+            fn first_layer(&self) -> (isize, isize) {
+                [$((self.$layer.width, self.$layer.height),)+][0]
+            }
+
+            #[allow(dead_code)]
+            pub fn width(&self) -> isize {
+                self.first_layer().0
+            }
+
+            #[allow(dead_code)]
+            pub fn height(&self) -> isize {
+                self.first_layer().1
+            }
+
+            #[allow(dead_code)]
+            pub fn out_of_bounds(&self, point: &$crate::Point) -> bool {
+                let (width, height) = self.first_layer();
+                point.x < 0 || point.x >= width || point.y < 0 || point.y >= height
+            }
+
+            /// Returns the values of all layers at `point`, in declaration order.
+            #[allow(dead_code)]
+            pub fn get(&self, point: $crate::Point) -> ($($cell,)+) {
+                ($(self.$layer.get(point),)+)
+            }
+
+            /// Returns the values of all layers for every point, in row-major order.
+            #[allow(dead_code)]
+            pub fn iter(&self) -> impl Iterator<Item = ($crate::Point, ($($cell,)+))> + '_ {
+                let (width, height) = self.first_layer();
+                (0..height).flat_map(move |y| {
+                    (0..width).map(move |x| {
+                        let point = $crate::Point { x, y };
+                        (point, self.get(point))
+                    })
+                })
+            }
+
+            /// Renders one line per row, without a trailing newline.
+            #[allow(dead_code)]
+            pub fn render(&self, background: char) -> String {
+                $(let $layer: fn(&$cell) -> Option<char> = $render;)+
+                let (width, height) = self.first_layer();
+                let mut output = String::with_capacity(((width + 1) * height) as usize);
+                for y in 0..height {
+                    if y > 0 {
+                        output.push('\n');
+                    }
+                    for x in 0..width {
+                        let point = $crate::Point { x, y };
+                        let c = None
+                            $(.or_else(|| $layer(&self.$layer.get(point))))+
+                            .unwrap_or(background);
+                        output.push(c);
+                    }
+                }
+                output
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, Grid, GridError, Point};
+
+    layered_grid! {
+        struct Lab {
+            guard: Option<Direction> {
+                set: set_guard,
+                replace: replace_guard,
+                render: |guard| guard.map(|_| '^'),
+            },
+            walls: bool {
+                set: set_wall,
+                replace: replace_walls,
+                render: |&wall| wall.then_some('#'),
+            },
+            visited: bool {
+                set: set_visited,
+                replace: replace_visited,
+                render: |&visited| visited.then_some('X'),
+            },
+        }
+    }
+
+    #[test]
+    pub fn test_layered_grid_access() {
+        let mut lab = Lab::new(3, 2);
+        assert_eq!(lab.width(), 3);
+        assert_eq!(lab.height(), 2);
+        assert!(lab.out_of_bounds(&Point { x: 3, y: 0 }));
+
+        let point = Point { x: 1, y: 1 };
+        lab.set_wall(point, true);
+        lab.set_visited(point, true);
+        assert_eq!(lab.get(point), (None, true, true));
+
+        let visited: Vec<_> = lab
+            .iter()
+            .filter(|(_, (_, _, visited))| *visited)
+            .map(|(point, _)| point)
+            .collect();
+        assert_eq!(visited, vec![point]);
+    }
+
+    #[test]
+    pub fn test_layered_grid_from_layers() {
+        let guard = Grid::new_empty(2, 2);
+        let walls = Grid::new(vec![vec![true, false], vec![false, false]]);
+        let lab = Lab::from_layers(guard, walls, Grid::new_empty(2, 2)).unwrap();
+        assert!(lab.walls().get(Point { x: 0, y: 0 }));
+
+        let result = Lab::from_layers(
+            Grid::new_empty(2, 2),
+            Grid::new_empty(2, 2),
+            Grid::new_empty(3, 2),
+        );
+        assert_eq!(
+            result,
+            Err(GridError::LayerMismatch {
+                layer: "visited",
+                expected: (2, 2),
+                found: (3, 2),
+            })
+        );
+    }
+
+    #[test]
+    pub fn test_layered_grid_replace() {
+        let mut lab = Lab::new(2, 2);
+        let walls = Grid::new(vec![vec![true, true], vec![false, false]]);
+        let previous = lab.replace_walls(walls.clone()).unwrap();
+        assert_eq!(previous, Grid::new_empty(2, 2));
+        assert_eq!(lab.walls(), &walls);
+
+        assert_eq!(
+            lab.replace_visited(Grid::new_empty(5, 5)),
+            Err(GridError::LayerMismatch {
+                layer: "visited",
+                expected: (2, 2),
+                found: (5, 5),
+            })
+        );
+        assert_eq!(lab.visited(), &Grid::new_empty(2, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "point (2, 0) is out of bounds for a 2x2 grid")]
+    pub fn test_layered_grid_set_out_of_bounds() {
+        Lab::new(2, 2).set_wall(Point { x: 2, y: 0 }, true);
+    }
+
+    #[test]
+    pub fn test_layered_grid_render() {
+        let mut lab = Lab::new(3, 2);
+        lab.set_wall(Point { x: 0, y: 0 }, true);
+        lab.set_visited(Point { x: 1, y: 0 }, true);
+        lab.set_visited(Point { x: 1, y: 1 }, true);
+        lab.set_guard(Point { x: 1, y: 1 }, Some(Direction::North));
+
+        assert_eq!(lab.render('.'), "#X.\n.^.");
+    }
+}
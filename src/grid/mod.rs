@@ -1,5 +1,23 @@
 mod base;
+mod builder;
+mod cell;
+mod contour;
+mod cursor;
+mod error;
 mod iterator;
+mod junctions;
+mod layered;
+mod neighbors;
+mod parallel;
+mod search;
+mod transform;
+mod traversal;
 
 pub use base::Grid;
-pub use iterator::GridIterator;
+pub use builder::{GridBuilder, PointStream};
+pub use cell::Cell;
+pub use cursor::{CursorStop, GridCursor};
+pub use error::GridError;
+pub use iterator::{GridIterator, Points, StopReason, TakeUntil, WithPoints};
+pub use neighbors::Neighborhood;
+pub use traversal::EdgeBehavior;
@@ -0,0 +1,204 @@
+use crate::{Direction, Grid, Point};
+use std::fmt::Debug;
+
+/// Which cells count as adjacent to a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neighborhood {
+    /// North, east, south and west.
+    Cardinal,
+    /// The cardinal directions plus the four diagonals.
+    All,
+}
+
+impl Neighborhood {
+    /// The directions of this neighbourhood, clockwise starting at north.
+    pub fn directions(&self) -> &'static [Direction] {
+        match self {
+            Neighborhood::Cardinal => &Direction::CARDINAL,
+            Neighborhood::All => &Direction::ALL,
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Returns the in-bounds neighbours of `point`, clockwise starting at north.
+    pub fn neighbors(
+        &self,
+        point: Point,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (Direction, Point, &T)> + '_ {
+        neighborhood
+            .directions()
+            .iter()
+            .filter_map(move |&direction| {
+                let next = point.translate_direction(direction);
+                if self.out_of_bounds(&next) {
+                    return None;
+                }
+                Some((
+                    direction,
+                    next,
+                    &self.cells[next.y as usize][next.x as usize],
+                ))
+            })
+    }
+
+    /// Like [`Grid::neighbors`], but yields `default` for neighbours outside the grid.
+    pub fn neighbors_or<'a>(
+        &'a self,
+        point: Point,
+        neighborhood: Neighborhood,
+        default: &'a T,
+    ) -> impl Iterator<Item = (Direction, Point, &'a T)> + 'a {
+        neighborhood.directions().iter().map(move |&direction| {
+            let next = point.translate_direction(direction);
+            if self.out_of_bounds(&next) {
+                return (direction, next, default);
+            }
+            (
+                direction,
+                next,
+                &self.cells[next.y as usize][next.x as usize],
+            )
+        })
+    }
+
+    /// Returns the cells within Manhattan distance `radius` of `point` together with their
+    /// distance, in reading order. See [`Point::within_manhattan`].
+    pub fn within_manhattan(
+        &self,
+        point: Point,
+        radius: usize,
+    ) -> impl Iterator<Item = (Point, usize, &T)> + '_ {
+        self.clip_with_distance(point.within_manhattan(radius))
+    }
+
+    /// Returns the cells within Chebyshev distance `radius` of `point` together with their
+    /// distance, in reading order. See [`Point::within_chebyshev`].
+    pub fn within_chebyshev(
+        &self,
+        point: Point,
+        radius: usize,
+    ) -> impl Iterator<Item = (Point, usize, &T)> + '_ {
+        self.clip_with_distance(point.within_chebyshev(radius))
+    }
+
+    fn clip_with_distance(
+        &self,
+        points: impl Iterator<Item = (Point, usize)> + 'static,
+    ) -> impl Iterator<Item = (Point, usize, &T)> + '_ {
+        points.filter_map(move |(point, distance)| {
+            if self.out_of_bounds(&point) {
+                return None;
+            }
+            Some((
+                point,
+                distance,
+                &self.cells[point.y as usize][point.x as usize],
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_neighbors_cardinal() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+        let center: Vec<_> = grid
+            .neighbors(Point { x: 1, y: 1 }, Neighborhood::Cardinal)
+            .collect();
+        assert_eq!(
+            center,
+            vec![
+                (Direction::North, Point { x: 1, y: 0 }, &2),
+                (Direction::East, Point { x: 2, y: 1 }, &6),
+                (Direction::South, Point { x: 1, y: 2 }, &8),
+                (Direction::West, Point { x: 0, y: 1 }, &4),
+            ]
+        );
+
+        let corner: Vec<_> = grid
+            .neighbors(Point { x: 0, y: 0 }, Neighborhood::Cardinal)
+            .map(|(_, _, value)| *value)
+            .collect();
+        assert_eq!(corner, vec![2, 4]);
+    }
+
+    #[test]
+    pub fn test_neighbors_all() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+        let center: Vec<_> = grid
+            .neighbors(Point { x: 1, y: 1 }, Neighborhood::All)
+            .map(|(_, _, value)| *value)
+            .collect();
+        assert_eq!(center, vec![2, 3, 6, 9, 8, 7, 4, 1]);
+
+        let corner: Vec<_> = grid
+            .neighbors(Point { x: 2, y: 2 }, Neighborhood::All)
+            .map(|(direction, _, _)| direction)
+            .collect();
+        assert_eq!(
+            corner,
+            vec![Direction::North, Direction::West, Direction::NorthWest]
+        );
+    }
+
+    #[test]
+    pub fn test_neighbors_or() {
+        let grid = Grid::new(vec![vec![1, 2], vec![3, 4]]);
+
+        let values: Vec<_> = grid
+            .neighbors_or(Point { x: 0, y: 0 }, Neighborhood::Cardinal, &0)
+            .map(|(_, point, value)| (point, *value))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                (Point { x: 0, y: -1 }, 0),
+                (Point { x: 1, y: 0 }, 2),
+                (Point { x: 0, y: 1 }, 3),
+                (Point { x: -1, y: 0 }, 0),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_within_manhattan() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+        let corner: Vec<_> = grid
+            .within_manhattan(Point { x: 0, y: 0 }, 2)
+            .map(|(_, distance, value)| (distance, *value))
+            .collect();
+        assert_eq!(corner, vec![(0, 1), (1, 2), (2, 3), (1, 4), (2, 5), (2, 7)]);
+        assert_eq!(grid.within_manhattan(Point { x: 1, y: 1 }, 5).count(), 9);
+    }
+
+    #[test]
+    pub fn test_within_chebyshev() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+        let corner: Vec<_> = grid
+            .within_chebyshev(Point { x: 2, y: 2 }, 1)
+            .map(|(point, distance, &value)| (point, distance, value))
+            .collect();
+        assert_eq!(
+            corner,
+            vec![
+                (Point { x: 1, y: 1 }, 1, 5),
+                (Point { x: 2, y: 1 }, 1, 6),
+                (Point { x: 1, y: 2 }, 1, 8),
+                (Point { x: 2, y: 2 }, 0, 9),
+            ]
+        );
+        assert_eq!(grid.within_chebyshev(Point { x: 5, y: 5 }, 2).count(), 0);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{par_map_reduce, Grid, Point};
+use std::fmt::Debug;
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq + Debug + Default + Sync,
+{
+    /// Maps every cell on scoped threads and reduces the results, see
+    /// [`par_map_reduce`](crate::par_map_reduce). Every worker handles a band of whole rows.
+    pub fn par_map_reduce<S, R, M, F>(&self, state: &S, identity: R, map: M, reduce: F) -> R
+    where
+        S: Clone + Send + Sync,
+        R: Clone + Send + Sync,
+        M: Fn(&mut S, Point, T) -> R + Sync,
+        F: Fn(R, R) -> R + Sync,
+    {
+        let rows: Vec<isize> = (0..self.height).collect();
+        par_map_reduce(
+            &rows,
+            state,
+            identity.clone(),
+            |state, &y| {
+                self.row(y)
+                    .with_points()
+                    .fold(identity.clone(), |acc, (point, value)| {
+                        reduce(acc, map(state, point, value))
+                    })
+            },
+            &reduce,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_grid_par_map_reduce() {
+        let grid = Grid::from_fn(50, 40, |point| point.x * point.y);
+        let total = grid.par_map_reduce(&(), 0, |_, _, value| value, |a, b| a + b);
+        assert_eq!(total, (0..50).sum::<isize>() * (0..40).sum::<isize>());
+
+        let points = grid.par_map_reduce(
+            &(),
+            Vec::new(),
+            |_, point, _| vec![point],
+            |mut a, b| {
+                a.extend(b);
+                a
+            },
+        );
+        let expected: Vec<Point> = grid.iter().with_points().map(|(p, _)| p).collect();
+        assert_eq!(points, expected);
+    }
+}
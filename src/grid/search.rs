@@ -0,0 +1,118 @@
+use crate::{Grid, Point};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Returns the first point in row-major order holding `value`.
+    pub fn find(&self, value: T) -> Option<Point> {
+        self.position(|&v| v == value)
+    }
+
+    /// Returns the first point in row-major order whose value matches `predicate`.
+    pub fn position<P>(&self, mut predicate: P) -> Option<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells.iter().enumerate().find_map(|(y, row)| {
+            row.iter().position(&mut predicate).map(|x| Point {
+                x: x as isize,
+                y: y as isize,
+            })
+        })
+    }
+
+    /// Returns every point holding `value`, in row-major order.
+    pub fn find_all(&self, value: T) -> Vec<Point> {
+        self.cells
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(move |(_, &v)| v == value)
+                    .map(move |(x, _)| Point {
+                        x: x as isize,
+                        y: y as isize,
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns how many cells hold `value`.
+    pub fn count(&self, value: T) -> usize {
+        self.cells
+            .iter()
+            .map(|row| row.iter().filter(|&&v| v == value).count())
+            .sum()
+    }
+
+    /// Returns how many cells hold each distinct value.
+    pub fn histogram(&self) -> HashMap<T, usize>
+    where
+        T: Hash + Eq,
+    {
+        let mut histogram = HashMap::new();
+        for &value in self.cells.iter().flatten() {
+            *histogram.entry(value).or_insert(0) += 1;
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::new(vec![
+            vec!['.', '#', '.'],
+            vec!['.', '^', '#'],
+            vec!['#', '.', '.'],
+        ])
+    }
+
+    #[test]
+    pub fn test_find() {
+        let grid = grid();
+        assert_eq!(grid.find('^'), Some(Point { x: 1, y: 1 }));
+        assert_eq!(grid.find('#'), Some(Point { x: 1, y: 0 }));
+        assert_eq!(grid.find('X'), None);
+    }
+
+    #[test]
+    pub fn test_position() {
+        let grid = grid();
+        assert_eq!(
+            grid.position(|&c| c != '.' && c != '#'),
+            Some(Point { x: 1, y: 1 })
+        );
+        assert_eq!(grid.position(|c| c.is_ascii_digit()), None);
+    }
+
+    #[test]
+    pub fn test_find_all() {
+        let grid = grid();
+        assert_eq!(
+            grid.find_all('#'),
+            vec![Point { x: 1, y: 0 }, Point { x: 2, y: 1 }, Point { x: 0, y: 2 }]
+        );
+        assert!(grid.find_all('X').is_empty());
+    }
+
+    #[test]
+    pub fn test_count_and_histogram() {
+        let grid = grid();
+        assert_eq!(grid.count('.'), 5);
+        assert_eq!(grid.count('X'), 0);
+
+        let histogram = grid.histogram();
+        assert_eq!(histogram.len(), 3);
+        assert_eq!(histogram[&'.'], 5);
+        assert_eq!(histogram[&'#'], 3);
+        assert_eq!(histogram[&'^'], 1);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{Grid, Point};
+use std::fmt::Debug;
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Creates a grid by calling `f` for every point, in row-major order.
+    pub fn from_fn<F>(width: isize, height: isize, mut f: F) -> Grid<T>
+    where
+        F: FnMut(Point) -> T,
+    {
+        let cells = (0..height)
+            .map(|y| (0..width).map(|x| f(Point { x, y })).collect())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Returns a grid of the same size with `f` applied to every value.
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        let cells = self
+            .cells
+            .iter()
+            .map(|row| row.iter().map(|&value| f(value)).collect())
+            .collect();
+
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    /// Combines two grids of identical size cell by cell.
+    pub fn zip_with<U, V, F>(&self, other: &Grid<U>, mut f: F) -> Grid<V>
+    where
+        U: Copy,
+        F: FnMut(T, U) -> V,
+    {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "cannot zip a {}x{} grid with a {}x{} grid",
+            self.width,
+            self.height,
+            other.width,
+            other.height
+        );
+
+        let cells = self
+            .cells
+            .iter()
+            .zip(other.cells.iter())
+            .map(|(a, b)| a.iter().zip(b.iter()).map(|(&a, &b)| f(a, b)).collect())
+            .collect();
+
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_from_fn() {
+        let grid = Grid::from_fn(3, 2, |point| point.x + point.y * 3);
+        assert_eq!(grid, Grid::new(vec![vec![0, 1, 2], vec![3, 4, 5]]));
+    }
+
+    #[test]
+    pub fn test_map() {
+        let grid = Grid::new(vec![vec!['1', '2'], vec!['3', '4']]);
+        let digits = grid.map(|c| c.to_digit(10).unwrap());
+        assert_eq!(digits, Grid::new(vec![vec![1, 2], vec![3, 4]]));
+    }
+
+    #[test]
+    pub fn test_zip_with() {
+        let a = Grid::new(vec![vec![1, 2], vec![3, 4]]);
+        let b = Grid::new(vec![vec![true, false], vec![false, true]]);
+        let zipped = a.zip_with(&b, |value, keep| if keep { value } else { 0 });
+        assert_eq!(zipped, Grid::new(vec![vec![1, 0], vec![0, 4]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot zip a 2x1 grid with a 1x2 grid")]
+    pub fn test_zip_with_mismatch() {
+        let a = Grid::new(vec![vec![1, 2]]);
+        let b = Grid::new(vec![vec![1], vec![2]]);
+        a.zip_with(&b, |a, b| a + b);
+    }
+}
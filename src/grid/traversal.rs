@@ -0,0 +1,197 @@
+use crate::{Grid, Point};
+use std::fmt::Debug;
+
+/// What an iterator does when its path leaves the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeBehavior {
+    /// Skip the points outside the grid and carry on until the grid is covered.
+    Clip,
+    /// End at the first point outside the grid.
+    Stop,
+}
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq + Debug + Default,
+{
+    /// Returns the outermost cells, clockwise from the top left corner.
+    pub fn border(&self) -> impl Iterator<Item = (Point, T)> + '_ {
+        self.ring(0)
+    }
+
+    /// Returns the cells `k` steps in from the border, clockwise from the top left corner of
+    /// the ring. Rings past the center of the grid are empty.
+    pub fn ring(&self, k: isize) -> impl Iterator<Item = (Point, T)> + '_ {
+        let (left, top) = (k, k);
+        let (right, bottom) = (self.width - 1 - k, self.height - 1 - k);
+        let exists = k >= 0 && left <= right && top <= bottom;
+
+        // Each side stops one cell short of the next corner; a single row or column has no
+        // way back.
+        let top_side = (left..=right).map(move |x| Point { x, y: top });
+        let right_side = (top + 1..=bottom).map(move |y| Point { x: right, y });
+        let bottom_side = (left..right)
+            .rev()
+            .filter(move |_| top < bottom)
+            .map(move |x| Point { x, y: bottom });
+        let left_side = (top + 1..bottom)
+            .rev()
+            .filter(move |_| left < right)
+            .map(move |y| Point { x: left, y });
+
+        top_side
+            .chain(right_side)
+            .chain(bottom_side)
+            .chain(left_side)
+            .filter(move |_| exists)
+            .map(|point| (point, self.get(point)))
+    }
+
+    /// Returns the cells on the segment from `a` to `b`, see [`Point::line_to`]. Points of the
+    /// segment outside the grid are skipped.
+    pub fn segment(&self, a: Point, b: Point) -> impl Iterator<Item = (Point, T)> + '_ {
+        a.line_to(b)
+            .filter(|p| !self.out_of_bounds(p))
+            .map(|p| (p, self.get(p)))
+    }
+
+    /// Returns the cells on the infinite line through `a` and `b`, see [`Point::line_through`].
+    pub fn line_through(&self, a: Point, b: Point) -> impl Iterator<Item = (Point, T)> + '_ {
+        a.line_through(b, self.bounds()).map(|p| (p, self.get(p)))
+    }
+
+    /// Returns the cells on a square spiral around `point`, see [`Point::spiral`].
+    ///
+    /// With [`EdgeBehavior::Stop`], a `point` outside the grid gives no cells. With
+    /// [`EdgeBehavior::Clip`], the spiral walks every point out to the farthest corner, which
+    /// takes long for a `point` far from the grid.
+    pub fn spiral_from(
+        &self,
+        point: Point,
+        edge: EdgeBehavior,
+    ) -> impl Iterator<Item = (Point, T)> + '_ {
+        // The spiral has covered the grid once it reaches the farthest corner.
+        let corners = [
+            (0, 0),
+            (self.width - 1, 0),
+            (0, self.height - 1),
+            (self.width - 1, self.height - 1),
+        ];
+        let radius = corners
+            .iter()
+            .map(|&(x, y)| point.chebyshev(Point { x, y }))
+            .max()
+            .unwrap();
+        let covering = radius.saturating_mul(2).saturating_add(1).saturating_pow(2);
+
+        point
+            .spiral()
+            .take(covering)
+            .take_while(move |p| edge == EdgeBehavior::Clip || !self.out_of_bounds(p))
+            .filter(|p| !self.out_of_bounds(p))
+            .map(|p| (p, self.get(p)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u32> {
+        Grid::from_fn(4, 3, |p| (p.x + p.y * 4) as u32)
+    }
+
+    #[test]
+    pub fn test_border() {
+        let values: Vec<_> = grid().border().map(|(_, v)| v).collect();
+        assert_eq!(values, vec![0, 1, 2, 3, 7, 11, 10, 9, 8, 4]);
+    }
+
+    #[test]
+    pub fn test_ring() {
+        let grid = grid();
+        let inner: Vec<_> = grid.ring(1).map(|(p, _)| p).collect();
+        assert_eq!(inner, vec![Point { x: 1, y: 1 }, Point { x: 2, y: 1 }]);
+        assert_eq!(grid.ring(2).count(), 0);
+        assert_eq!(grid.ring(-1).count(), 0);
+
+        let column = Grid::from_fn(1, 3, |p| p.y);
+        let values: Vec<_> = column.ring(0).map(|(_, v)| v).collect();
+        assert_eq!(values, vec![0, 1, 2]);
+
+        let square = Grid::from_fn(2, 2, |p| p.x + p.y * 2);
+        let values: Vec<_> = square.ring(0).map(|(_, v)| v).collect();
+        assert_eq!(values, vec![0, 1, 3, 2]);
+    }
+
+    #[test]
+    pub fn test_spiral_clip() {
+        let grid = grid();
+        let values: Vec<_> = grid
+            .spiral_from(Point { x: 0, y: 0 }, EdgeBehavior::Clip)
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(values.len(), 12);
+        assert_eq!(values[..4], [0, 1, 5, 4]);
+
+        let mut sorted = values.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    pub fn test_spiral_stop() {
+        let grid = grid();
+        let values: Vec<_> = grid
+            .spiral_from(Point { x: 0, y: 0 }, EdgeBehavior::Stop)
+            .map(|(_, v)| v)
+            .collect();
+        // The fifth point of the spiral is (-1, 1).
+        assert_eq!(values, vec![0, 1, 5, 4]);
+
+        let values: Vec<_> = grid
+            .spiral_from(Point { x: 1, y: 1 }, EdgeBehavior::Stop)
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(values, vec![5, 6, 10, 9, 8, 4, 0, 1, 2, 3, 7, 11]);
+    }
+
+    #[test]
+    pub fn test_spiral_far_outside() {
+        let grid = grid();
+        let far = Point { x: 1 << 40, y: 0 };
+        assert_eq!(grid.spiral_from(far, EdgeBehavior::Stop).count(), 0);
+
+        // Clipping skips the points outside the grid until the spiral reaches it.
+        let near = Point { x: 5, y: -1 };
+        let mut clipped = grid.spiral_from(near, EdgeBehavior::Clip);
+        assert_eq!(clipped.next(), Some((Point { x: 3, y: 1 }, 7)));
+        assert_eq!(clipped.count(), 11);
+    }
+
+    #[test]
+    pub fn test_segment() {
+        let grid = grid();
+        let values: Vec<_> = grid
+            .segment(Point { x: 0, y: 0 }, Point { x: 3, y: 2 })
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(values, vec![0, 5, 6, 11]);
+
+        let clipped: Vec<_> = grid
+            .segment(Point { x: -2, y: 1 }, Point { x: 5, y: 1 })
+            .map(|(p, _)| p.x)
+            .collect();
+        assert_eq!(clipped, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    pub fn test_line_through() {
+        let grid = grid();
+        let values: Vec<_> = grid
+            .line_through(Point { x: 2, y: 1 }, Point { x: 3, y: 2 })
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(values, vec![1, 6, 11]);
+    }
+}
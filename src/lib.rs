@@ -1,6 +1,24 @@
+mod graph;
 mod grid;
+mod math;
+mod parallel;
 mod point;
+mod polygon;
+mod rect;
+mod simulation;
 
 pub mod template;
-pub use grid::{Grid, GridIterator};
-pub use point::{Direction, Point};
+pub use graph::{dijkstra, Edge, Graph};
+pub use grid::{
+    Cell, CursorStop, EdgeBehavior, Grid, GridBuilder, GridCursor, GridError, GridIterator,
+    Neighborhood, PointStream, Points, StopReason, TakeUntil, WithPoints,
+};
+pub use math::gcd;
+pub use parallel::par_map_reduce;
+pub use point::{
+    sort_by_distance_from, Coordinate, Direction, GenericPoint, Metric, ParseDirectionError,
+    ParsePointError, Point, Spiral,
+};
+pub use polygon::Polygon;
+pub use rect::Rect;
+pub use simulation::{simulate, Cycle, Simulation};
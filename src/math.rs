@@ -0,0 +1,27 @@
+/// Returns the greatest common divisor of `a` and `b`. The divisor of `0` and `0` is `0`.
+///
+/// It is unsigned because the divisor of `i128::MIN` and `0` does not fit an `i128`.
+pub fn gcd(a: i128, b: i128) -> u128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, -7), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(i128::MIN, 0), 1 << 127);
+        assert_eq!(gcd(i128::MIN, -1), 1);
+        assert_eq!(gcd(i128::MIN, i128::MIN + 2), 2);
+    }
+}
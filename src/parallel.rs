@@ -0,0 +1,107 @@
+use std::thread;
+
+/// The number of chunks [`par_map_reduce`] splits its items into. It is fixed rather than
+/// taken from the core count, so the results are grouped the same way on every machine.
+const CHUNKS: usize = 64;
+
+/// Maps every item on scoped threads and reduces the results.
+///
+/// `items` is split into at most 64 contiguous chunks, each handled by its own
+/// worker. Every worker gets its own clone of `state` to mutate, and folds its chunk
+/// starting from `identity`. The chunk results are then folded in order on the calling
+/// thread, again starting from `identity`.
+///
+/// The grouping does not depend on the machine, so the result is always the same. It only
+/// equals a sequential fold if `reduce` is associative and `identity` is neutral, meaning
+/// `reduce(identity, x) == x`.
+pub fn par_map_reduce<I, S, R, M, F>(items: &[I], state: &S, identity: R, map: M, reduce: F) -> R
+where
+    I: Sync,
+    S: Clone + Send + Sync,
+    R: Clone + Send,
+    M: Fn(&mut S, &I) -> R + Sync,
+    F: Fn(R, R) -> R + Sync,
+{
+    if items.is_empty() {
+        return identity;
+    }
+
+    let chunk_size = items.len().div_ceil(CHUNKS);
+
+    let results: Vec<R> = thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let (map, reduce) = (&map, &reduce);
+                let identity = identity.clone();
+                scope.spawn(move || {
+                    let mut state = state.clone();
+                    chunk
+                        .iter()
+                        .fold(identity, |acc, item| reduce(acc, map(&mut state, item)))
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    results.into_iter().fold(identity, reduce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_map_reduce_sum() {
+        let items: Vec<u64> = (1..=10_000).collect();
+        let total = par_map_reduce(&items, &(), 0, |_, &x| x, |a, b| a + b);
+        assert_eq!(total, 50_005_000);
+    }
+
+    #[test]
+    fn test_par_map_reduce_is_ordered() {
+        let items: Vec<usize> = (0..1000).collect();
+        let joined = par_map_reduce(
+            &items,
+            &(),
+            Vec::new(),
+            |_, &x| vec![x],
+            |mut a, b| {
+                a.extend(b);
+                a
+            },
+        );
+        assert_eq!(joined, items);
+    }
+
+    #[test]
+    fn test_par_map_reduce_state_is_per_worker() {
+        // Every item restores the flag it sets, so each one sees exactly one flag set.
+        let items: Vec<usize> = (0..100).collect();
+        let state = vec![false; 100];
+        let count = par_map_reduce(
+            &items,
+            &state,
+            0,
+            |state, &i| {
+                state[i] = true;
+                let set = state.iter().filter(|&&x| x).count();
+                state[i] = false;
+                set
+            },
+            |a, b| a + b,
+        );
+        assert_eq!(count, 100);
+    }
+
+    #[test]
+    fn test_par_map_reduce_empty() {
+        let items: Vec<u8> = Vec::new();
+        assert_eq!(par_map_reduce(&items, &(), 7, |_, _| 1, |a, b| a + b), 7);
+    }
+}
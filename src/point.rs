@@ -1,30 +1,435 @@
+use crate::{gcd, Rect};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A signed integer type that can be used for the coordinates of a [`Point`].
+pub trait Coordinate:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// Widens the coordinate, which is lossless for every signed type up to `i128`.
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),+) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+            }
+        )+
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize);
+
+/// A point on the integer plane with coordinates of any signed width, for puzzles with huge
+/// or small coordinates. Most code uses [`Point`], whose `isize` coordinates are what grids
+/// are indexed with.
+///
+/// Arithmetic, conversions, [`Rect`] and [`Polygon`](crate::Polygon) work with any
+/// [`Coordinate`]. The helpers that walk the plane cell by cell (directions, neighbours,
+/// distances and lines) are only available for [`Point`].
 #[derive(Debug, Clone, PartialEq, PartialOrd, Copy, Hash, Eq)]
-pub struct Point {
-    pub x: isize,
-    pub y: isize,
+pub struct GenericPoint<T> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Point {
-    pub fn offset(&self, dx: isize, dy: isize) -> Point {
-        Point {
+/// A point with `isize` coordinates, which is what grids are indexed with.
+pub type Point = GenericPoint<isize>;
+
+impl<T> GenericPoint<T>
+where
+    T: Coordinate,
+{
+    pub fn offset(&self, dx: T, dy: T) -> GenericPoint<T> {
+        GenericPoint {
             x: self.x + dx,
             y: self.y + dy,
         }
     }
 
-    pub fn translate_offset(&self, other: Point) -> Point {
+    pub fn translate_offset(&self, other: GenericPoint<T>) -> GenericPoint<T> {
         let (dx, dy) = self.delta(other);
         self.offset(dx, dy)
     }
 
-    pub fn delta(&self, other: Point) -> (isize, isize) {
+    pub fn delta(&self, other: GenericPoint<T>) -> (T, T) {
         (self.x - other.x, self.y - other.y)
     }
 
+    /// Like adding the points, but returns `None` instead of overflowing.
+    pub fn checked_add(&self, other: GenericPoint<T>) -> Option<GenericPoint<T>> {
+        self.checked_offset(other.x, other.y)
+    }
+
+    /// Like [`Point::offset`], but returns `None` instead of overflowing.
+    pub fn checked_offset(&self, dx: T, dy: T) -> Option<GenericPoint<T>> {
+        Some(GenericPoint {
+            x: self.x.checked_add(dx)?,
+            y: self.y.checked_add(dy)?,
+        })
+    }
+}
+
+impl Point {
     pub fn translate_direction(&self, direction: Direction) -> Point {
         let (dx, dy) = direction.delta();
         self.offset(dx, dy)
     }
+
+    /// The number of orthogonal steps between two points.
+    pub fn manhattan(&self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of king's moves between two points. This cannot overflow.
+    pub fn chebyshev(&self, other: Point) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The squared straight-line distance, which keeps the comparison of distances exact.
+    pub fn euclidean_sq(&self, other: Point) -> usize {
+        let (dx, dy) = (self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+        dx * dx + dy * dy
+    }
+
+    /// Like [`Point::manhattan`], but returns `None` instead of overflowing.
+    pub fn checked_manhattan(&self, other: Point) -> Option<usize> {
+        self.x
+            .abs_diff(other.x)
+            .checked_add(self.y.abs_diff(other.y))
+    }
+
+    /// Like [`Point::euclidean_sq`], but returns `None` instead of overflowing.
+    pub fn checked_euclidean_sq(&self, other: Point) -> Option<usize> {
+        let (dx, dy) = (self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+        dx.checked_mul(dx)?.checked_add(dy.checked_mul(dy)?)
+    }
+
+    /// The distance to `other` in the given metric.
+    pub fn distance(&self, other: Point, metric: Metric) -> usize {
+        match metric {
+            Metric::Manhattan => self.manhattan(other),
+            Metric::Chebyshev => self.chebyshev(other),
+            Metric::EuclideanSq => self.euclidean_sq(other),
+        }
+    }
+
+    /// Returns the four orthogonally adjacent points, clockwise starting at north.
+    pub fn neighbors4(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        Direction::CARDINAL
+            .iter()
+            .map(move |&direction| point.translate_direction(direction))
+    }
+
+    /// Returns the eight adjacent points including diagonals, clockwise starting at north.
+    pub fn neighbors8(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        Direction::ALL
+            .iter()
+            .map(move |&direction| point.translate_direction(direction))
+    }
+
+    /// Returns the points of the segment from this point to `other`, both ends included, as
+    /// rasterised by Bresenham's algorithm. Consecutive points touch, diagonals included.
+    pub fn line_to(&self, other: Point) -> impl Iterator<Item = Point> {
+        let (dx, dy) = ((other.x - self.x).abs(), -(other.y - self.y).abs());
+        let (sx, sy) = ((other.x - self.x).signum(), (other.y - self.y).signum());
+        let mut error = dx + dy;
+        let mut next = Some(*self);
+
+        std::iter::from_fn(move || {
+            let point = next?;
+            if point == other {
+                next = None;
+                return Some(point);
+            }
+
+            let mut step = point;
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                step.x += sx;
+            }
+            if doubled <= dx {
+                error += dx;
+                step.y += sy;
+            }
+            next = Some(step);
+            Some(point)
+        })
+    }
+
+    /// Returns only the points with integer coordinates that lie exactly on the segment from
+    /// this point to `other`, both ends included.
+    pub fn lattice_line_to(&self, other: Point) -> impl Iterator<Item = Point> {
+        let start = *self;
+        let (step, steps) = match self.direction_to(other) {
+            Some(step) if step.x != 0 => (step, (other.x - start.x) / step.x),
+            Some(step) => (step, (other.y - start.y) / step.y),
+            None => (Point { x: 0, y: 0 }, 0),
+        };
+        (0..=steps).map(move |k| start + step * k)
+    }
+
+    /// Returns the smallest step from this point towards `other` that lands on integer
+    /// coordinates, i.e. the difference divided by the gcd of its components. Repeating
+    /// the step visits every lattice point on the way. Returns `None` if the points are equal.
+    pub fn direction_to(&self, other: Point) -> Option<Point> {
+        let difference = other - *self;
+        let (dx, dy) = (difference.x as i128, difference.y as i128);
+        // The divisor of `isize::MIN` and `0` does not fit an `isize`, but does fit an `i128`.
+        let divisor = gcd(dx, dy) as i128;
+        (divisor != 0).then(|| Point {
+            x: (dx / divisor) as isize,
+            y: (dy / divisor) as isize,
+        })
+    }
+
+    /// Returns every lattice point inside `bounds` on the infinite line through this point
+    /// and `other`, ordered in the direction from this point to `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the points are equal, as they do not define a line.
+    pub fn line_through(&self, other: Point, bounds: Rect) -> impl Iterator<Item = Point> {
+        let step = self
+            .direction_to(other)
+            .expect("a line needs two distinct points");
+
+        // The multiples k of the step for which `self + step * k` lies within the bounds on
+        // one axis, as an inclusive range.
+        let axis = |position: isize, step: isize, min: isize, max: isize| match step {
+            0 if (min..=max).contains(&position) => (isize::MIN, isize::MAX),
+            0 => (1, 0),
+            s if s > 0 => (ceil_div(min - position, s), (max - position).div_euclid(s)),
+            s => (
+                ceil_div(position - max, -s),
+                (position - min).div_euclid(-s),
+            ),
+        };
+        let (x_from, x_to) = axis(self.x, step.x, bounds.min.x, bounds.max.x);
+        let (y_from, y_to) = axis(self.y, step.y, bounds.min.y, bounds.max.y);
+
+        let start = *self;
+        (x_from.max(y_from)..=x_to.min(y_to)).map(move |k| start + step * k)
+    }
+
+    /// Whether the three points lie on one line.
+    pub fn is_collinear(&self, b: Point, c: Point) -> bool {
+        let (ab, ac) = (b - *self, c - *self);
+        ab.x as i128 * ac.y as i128 == ab.y as i128 * ac.x as i128
+    }
+
+    /// Returns every point within Manhattan distance `radius` (a diamond) together with its
+    /// distance, in reading order.
+    pub fn within_manhattan(&self, radius: usize) -> impl Iterator<Item = (Point, usize)> {
+        let center = *self;
+        let r = radius as isize;
+        (-r..=r).flat_map(move |dy| {
+            let span = r - dy.abs();
+            (-span..=span).map(move |dx| (center.offset(dx, dy), (dx.abs() + dy.abs()) as usize))
+        })
+    }
+
+    /// Returns every point within Chebyshev distance `radius` (a square) together with its
+    /// distance, in reading order.
+    pub fn within_chebyshev(&self, radius: usize) -> impl Iterator<Item = (Point, usize)> {
+        let center = *self;
+        let r = radius as isize;
+        (-r..=r).flat_map(move |dy| {
+            (-r..=r).map(move |dx| {
+                let point = center.offset(dx, dy);
+                (point, point.chebyshev(center))
+            })
+        })
+    }
+
+    /// Returns an endless square spiral around this point, starting with the point itself
+    /// and then stepping east and turning clockwise. The first `(2r + 1)²` points cover
+    /// every point within `r` steps, diagonals included.
+    pub fn spiral(&self) -> Spiral {
+        Spiral {
+            next: *self,
+            heading: Direction::East,
+            leg_length: 1,
+            walked: 0,
+            legs: 0,
+        }
+    }
+}
+
+fn ceil_div(a: isize, b: isize) -> isize {
+    -(-a).div_euclid(b)
+}
+
+/// A way of measuring the distance between two points, see [`Point::distance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Metric {
+    Manhattan,
+    Chebyshev,
+    EuclideanSq,
+}
+
+/// Sorts points by their distance from `origin`, nearest first. Points at the same distance
+/// keep their order.
+pub fn sort_by_distance_from(points: &mut [Point], origin: Point, metric: Metric) {
+    points.sort_by_key(|point| point.distance(origin, metric));
+}
+
+impl<T: Coordinate> Add for GenericPoint<T> {
+    type Output = GenericPoint<T>;
+
+    fn add(self, other: GenericPoint<T>) -> GenericPoint<T> {
+        self.offset(other.x, other.y)
+    }
+}
+
+impl<T: Coordinate> Sub for GenericPoint<T> {
+    type Output = GenericPoint<T>;
+
+    fn sub(self, other: GenericPoint<T>) -> GenericPoint<T> {
+        let (dx, dy) = self.delta(other);
+        GenericPoint { x: dx, y: dy }
+    }
+}
+
+impl<T: Coordinate> Neg for GenericPoint<T> {
+    type Output = GenericPoint<T>;
+
+    fn neg(self) -> GenericPoint<T> {
+        GenericPoint {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: Coordinate> Mul<T> for GenericPoint<T> {
+    type Output = GenericPoint<T>;
+
+    fn mul(self, factor: T) -> GenericPoint<T> {
+        GenericPoint {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+impl<T: Coordinate> AddAssign for GenericPoint<T> {
+    fn add_assign(&mut self, other: GenericPoint<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> SubAssign for GenericPoint<T> {
+    fn sub_assign(&mut self, other: GenericPoint<T>) {
+        *self = *self - other;
+    }
+}
+
+macro_rules! try_from_point {
+    ($($from:ty => $($to:ty),+;)+) => {
+        $($(
+            impl TryFrom<GenericPoint<$from>> for GenericPoint<$to> {
+                type Error = TryFromIntError;
+
+                fn try_from(point: GenericPoint<$from>) -> Result<Self, Self::Error> {
+                    Ok(GenericPoint {
+                        x: point.x.try_into()?,
+                        y: point.y.try_into()?,
+                    })
+                }
+            }
+        )+)+
+    };
+}
+
+try_from_point! {
+    i8 => i16, i32, i64, i128, isize;
+    i16 => i8, i32, i64, i128, isize;
+    i32 => i8, i16, i64, i128, isize;
+    i64 => i8, i16, i32, i128, isize;
+    i128 => i8, i16, i32, i64, isize;
+    isize => i8, i16, i32, i64, i128;
+}
+
+/// The unit step in a direction, e.g. `(1, -1)` for north east.
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Point {
+        let (x, y) = direction.delta();
+        Point { x, y }
+    }
+}
+
+/// An endless square spiral, see [`Point::spiral`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spiral {
+    next: Point,
+    heading: Direction,
+    leg_length: usize,
+    walked: usize,
+    legs: usize,
+}
+
+impl Iterator for Spiral {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.next;
+        self.next = point.translate_direction(self.heading);
+        self.walked += 1;
+
+        // Every two legs of the spiral, the legs become one step longer.
+        if self.walked == self.leg_length {
+            self.walked = 0;
+            self.heading = self.heading.turn_right();
+            self.legs += 1;
+            if self.legs.is_multiple_of(2) {
+                self.leg_length += 1;
+            }
+        }
+
+        Some(point)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Copy, Hash, Eq)]
@@ -40,6 +445,30 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// North, east, south and west.
+    pub const CARDINAL: [Direction; 4] =
+        [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// North east, south east, south west and north west.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthWest,
+    ];
+
+    /// Every direction clockwise from north, in declaration order.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
     pub fn delta(&self) -> (isize, isize) {
         match self {
             Direction::North => (0, -1),
@@ -52,11 +481,193 @@ impl Direction {
             Direction::NorthWest => (-1, -1),
         }
     }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(&self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Turns 90 degrees counterclockwise.
+    pub fn turn_left(&self) -> Direction {
+        self.rotate(6)
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right_45(&self) -> Direction {
+        self.rotate(1)
+    }
+
+    /// Turns 45 degrees counterclockwise.
+    pub fn turn_left_45(&self) -> Direction {
+        self.rotate(7)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.rotate(4)
+    }
+
+    /// Whether this is north, east, south or west.
+    pub fn is_cardinal(&self) -> bool {
+        (*self as usize).is_multiple_of(2)
+    }
+
+    /// Turns clockwise by `eighths` of a full turn.
+    fn rotate(&self, eighths: usize) -> Direction {
+        Direction::ALL[(*self as usize + eighths) % 8]
+    }
+}
+
+/// Parses arrows (`^>v<`), compass letters (`NESW`) and moves (`UDLR`).
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' | 'U' => Ok(Direction::North),
+            '>' | 'E' | 'R' => Ok(Direction::East),
+            'v' | 'S' | 'D' => Ok(Direction::South),
+            '<' | 'W' | 'L' => Ok(Direction::West),
+            found => Err(ParseDirectionError { found }),
+        }
+    }
+}
+
+/// A character that does not describe a [`Direction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDirectionError {
+    pub found: char,
+}
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid direction {:?}", self.found)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// Parses a pair of coordinates such as `3,4`, `<3, 4>`, `(3, 4)`, `x=3, y=4` or `p=0,4`.
+/// Every coordinate may carry a `name=` label, and the pair may be wrapped in angle brackets
+/// or parentheses.
+impl<T> FromStr for GenericPoint<T>
+where
+    T: Coordinate + FromStr,
+{
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParsePointError {
+            text: s.to_string(),
+        };
+
+        let trimmed = s.trim();
+        let inner = [('<', '>'), ('(', ')')]
+            .iter()
+            .find_map(|&(open, close)| trimmed.strip_prefix(open)?.strip_suffix(close))
+            .unwrap_or(trimmed);
+        let (x, y) = inner.split_once(',').ok_or_else(error)?;
+
+        let coordinate = |text: &str| {
+            let text = text.trim();
+            let is_label = |label: &str| {
+                let label = label.trim();
+                !label.is_empty() && label.chars().all(char::is_alphabetic)
+            };
+            let value = match text.split_once('=') {
+                Some((label, value)) if is_label(label) => value,
+                _ => text,
+            };
+            value.trim().parse::<T>().map_err(|_| error())
+        };
+        Ok(GenericPoint {
+            x: coordinate(x)?,
+            y: coordinate(y)?,
+        })
+    }
+}
+
+impl<T> GenericPoint<T>
+where
+    T: Coordinate + FromStr,
+{
+    /// Returns every point in `line`, reading its signed integers in pairs and ignoring
+    /// everything around them. A robot line such as `p=0,4 v=3,-3` gives two points.
+    ///
+    /// A `-` directly after a digit separates two numbers instead of being a sign, so `1-5`
+    /// reads as `1` and `5`.
+    ///
+    /// Fails if the line holds an odd number of integers or one that does not fit `T`.
+    pub fn extract_all(line: &str) -> Result<Vec<GenericPoint<T>>, ParsePointError> {
+        let is_digit = |c: char| c.is_ascii_digit();
+        let mut numbers = Vec::new();
+        let mut offset = 0;
+        while let Some(found) = line[offset..].find(is_digit) {
+            let start = offset + found;
+            let before = &line[..start];
+            let negative = before
+                .strip_suffix('-')
+                .is_some_and(|before| !before.ends_with(is_digit));
+            let end = line[start..]
+                .find(|c: char| !is_digit(c))
+                .map_or(line.len(), |length| start + length);
+
+            let token = &line[start - negative as usize..end];
+            let number = token.parse::<T>().map_err(|_| ParsePointError {
+                text: token.to_string(),
+            })?;
+            numbers.push(number);
+            offset = end;
+        }
+
+        if numbers.len() % 2 != 0 {
+            return Err(ParsePointError {
+                text: line.to_string(),
+            });
+        }
+        Ok(numbers
+            .chunks(2)
+            .map(|pair| GenericPoint {
+                x: pair[0],
+                y: pair[1],
+            })
+            .collect())
+    }
+
+    /// Returns the points in `line` in pairs, see [`GenericPoint::extract_all`]. A robot
+    /// line such as `p=0,4 v=3,-3` gives its position and velocity.
+    ///
+    /// Fails if the line holds a number of integers that is not a multiple of four, or one
+    /// that does not fit `T`.
+    pub fn extract_pairs(line: &str) -> Result<Vec<(Self, Self)>, ParsePointError> {
+        let points = Self::extract_all(line)?;
+        if points.len() % 2 != 0 {
+            return Err(ParsePointError {
+                text: line.to_string(),
+            });
+        }
+        Ok(points.chunks(2).map(|pair| (pair[0], pair[1])).collect())
+    }
+}
+
+/// Text that does not describe a [`Point`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePointError {
+    /// The offending part of the input.
+    pub text: String,
+}
+
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cannot parse a point from {:?}", self.text)
+    }
 }
 
+impl Error for ParsePointError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     #[test]
     fn test_offset() {
@@ -69,4 +680,440 @@ mod tests {
         assert_eq!(point_a.translate_offset(point_b), Point { x: 1, y: 1 });
         assert_eq!(point_b.translate_offset(point_a), Point { x: 7, y: 7 });
     }
+
+    #[test]
+    fn test_operators() {
+        let a = Point { x: 3, y: -2 };
+        let b = Point { x: 1, y: 4 };
+
+        assert_eq!(a + b, Point { x: 4, y: 2 });
+        assert_eq!(a - b, Point { x: 2, y: -6 });
+        assert_eq!(-a, Point { x: -3, y: 2 });
+        assert_eq!(b * 3, Point { x: 3, y: 12 });
+
+        // The operators agree with the older methods.
+        assert_eq!(a + (a - b), a.translate_offset(b));
+        let (dx, dy) = a.delta(b);
+        assert_eq!(a - b, Point { x: dx, y: dy });
+        assert_eq!(
+            b + Direction::SouthWest.into(),
+            b.translate_direction(Direction::SouthWest)
+        );
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!(Point::from(Direction::North), Point { x: 0, y: -1 });
+    }
+
+    #[test]
+    fn test_generic_points() {
+        // Literals of plain points keep their `isize` coordinates.
+        let plain = Point { x: 1, y: 2 }.offset(1, 1);
+        assert_eq!(std::any::type_name_of_val(&plain.x), "isize");
+
+        let a: GenericPoint<i64> = GenericPoint { x: 1 << 40, y: -3 };
+        let b = GenericPoint { x: 2, y: 5 };
+        assert_eq!(
+            a + b,
+            GenericPoint {
+                x: (1 << 40) + 2,
+                y: 2
+            }
+        );
+        assert_eq!(a.delta(b), ((1 << 40) - 2, -8));
+
+        let small: GenericPoint<i8> = GenericPoint { x: 100, y: 0 };
+        assert_eq!(
+            small.checked_offset(27, 0),
+            Some(GenericPoint { x: 127, y: 0 })
+        );
+        assert_eq!(small.checked_offset(28, 0), None);
+        assert_eq!(
+            small.checked_add(GenericPoint { x: 0, y: -128 }),
+            Some(GenericPoint { x: 100, y: -128 })
+        );
+
+        let point = Point { x: 3, y: -4 };
+        assert_eq!(
+            GenericPoint::<i128>::try_from(point),
+            Ok(GenericPoint { x: 3, y: -4 })
+        );
+        assert_eq!(
+            Point::try_from(GenericPoint::<i8>::try_from(point).unwrap()),
+            Ok(point)
+        );
+        assert!(GenericPoint::<i8>::try_from(Point { x: 300, y: 0 }).is_err());
+    }
+
+    #[test]
+    fn test_parse_point() {
+        let expected = Point { x: 3, y: -4 };
+        for text in ["3,-4", " 3, -4 ", "<3, -4>", "(3,-4)", "x=3, y=-4", "x = 3, y = -4", "p=3,-4"]
+        {
+            assert_eq!(text.parse::<Point>(), Ok(expected), "{text}");
+        }
+        assert_eq!(
+            "< 1,2 >".parse::<GenericPoint<i8>>(),
+            Ok(GenericPoint { x: 1, y: 2 })
+        );
+
+        for text in ["3 4", "3,", "3,4,5", "x=a, y=4", "=3, 4", "x=3, =4", "300,0"] {
+            let error = text.parse::<GenericPoint<i8>>().unwrap_err();
+            assert_eq!(error.text, text);
+        }
+        assert_eq!(
+            "1;2".parse::<Point>().unwrap_err().to_string(),
+            "cannot parse a point from \"1;2\""
+        );
+    }
+
+    #[test]
+    fn test_extract_all() {
+        let robot = Point::extract_all("p=0,4 v=3,-3").unwrap();
+        assert_eq!(robot, vec![Point { x: 0, y: 4 }, Point { x: 3, y: -3 }]);
+
+        let sensor: Vec<Point> =
+            Point::extract_all("Sensor at x=-2, y=18: closest beacon is at x=-10, y=20").unwrap();
+        assert_eq!(
+            sensor,
+            vec![Point { x: -2, y: 18 }, Point { x: -10, y: 20 }]
+        );
+        assert_eq!(Point::extract_all("no numbers"), Ok(vec![]));
+
+        // Only a minus sign that does not follow a digit makes a number negative.
+        let range = Point::extract_all("1-5 -2--7").unwrap();
+        assert_eq!(range, vec![Point { x: 1, y: 5 }, Point { x: -2, y: -7 }]);
+
+        let odd = Point::extract_all("1,2 3").unwrap_err();
+        assert_eq!(odd.text, "1,2 3");
+        let overflow = GenericPoint::<i8>::extract_all("p=1,-200").unwrap_err();
+        assert_eq!(overflow.text, "-200");
+    }
+
+    #[test]
+    fn test_extract_pairs() {
+        let robots = Point::extract_pairs("p=0,4 v=3,-3").unwrap();
+        assert_eq!(robots, vec![(Point { x: 0, y: 4 }, Point { x: 3, y: -3 })]);
+
+        let error = Point::extract_pairs("p=0,4 v=3,-3 q=1,1").unwrap_err();
+        assert_eq!(error.text, "p=0,4 v=3,-3 q=1,1");
+        let odd = Point::extract_pairs("p=0,4 v=3").unwrap_err();
+        assert_eq!(odd.text, "p=0,4 v=3");
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point { x: 1, y: -2 };
+        let b = Point { x: -3, y: 1 };
+
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.euclidean_sq(b), 25);
+        assert_eq!(b.distance(a, Metric::Manhattan), 7);
+        assert_eq!(a.checked_manhattan(b), Some(7));
+        assert_eq!(a.checked_euclidean_sq(b), Some(25));
+
+        let min = Point {
+            x: isize::MIN,
+            y: isize::MIN,
+        };
+        let max = Point {
+            x: isize::MAX,
+            y: isize::MAX,
+        };
+        assert_eq!(min.chebyshev(max), usize::MAX);
+        assert_eq!(min.checked_manhattan(max), None);
+        assert_eq!(min.checked_euclidean_sq(a), None);
+    }
+
+    #[test]
+    fn test_sort_by_distance_from() {
+        let origin = Point { x: 0, y: 0 };
+        let mut points = vec![
+            Point { x: 2, y: 2 },
+            Point { x: 3, y: 0 },
+            Point { x: 0, y: -1 },
+            Point { x: -3, y: 0 },
+        ];
+
+        sort_by_distance_from(&mut points, origin, Metric::Manhattan);
+        assert_eq!(
+            points,
+            vec![
+                Point { x: 0, y: -1 },
+                Point { x: 3, y: 0 },
+                Point { x: -3, y: 0 },
+                Point { x: 2, y: 2 },
+            ]
+        );
+
+        sort_by_distance_from(&mut points, origin, Metric::Chebyshev);
+        assert_eq!(
+            points[1..],
+            [Point { x: 2, y: 2 }, Point { x: 3, y: 0 }, Point { x: -3, y: 0 }]
+        );
+
+        // 8 is less than 9, and the two points at distance 9 stay in place.
+        let before = points.clone();
+        sort_by_distance_from(&mut points, origin, Metric::EuclideanSq);
+        assert_eq!(points, before);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let point = Point { x: 0, y: 0 };
+        assert_eq!(
+            point.neighbors4().collect::<Vec<_>>(),
+            vec![
+                Point { x: 0, y: -1 },
+                Point { x: 1, y: 0 },
+                Point { x: 0, y: 1 },
+                Point { x: -1, y: 0 },
+            ]
+        );
+
+        let all: Vec<_> = point.neighbors8().collect();
+        assert_eq!(all.len(), 8);
+        assert_eq!(all[1], Point { x: 1, y: -1 });
+        assert!(all.iter().all(|p| p.x.abs().max(p.y.abs()) == 1));
+    }
+
+    #[test]
+    fn test_line_to() {
+        let start = Point { x: 0, y: 0 };
+        let line: Vec<_> = start.line_to(Point { x: 4, y: 2 }).collect();
+        assert_eq!(
+            line,
+            vec![
+                Point { x: 0, y: 0 },
+                Point { x: 1, y: 1 },
+                Point { x: 2, y: 1 },
+                Point { x: 3, y: 2 },
+                Point { x: 4, y: 2 },
+            ]
+        );
+
+        let diagonal: Vec<_> = Point { x: 3, y: 3 }.line_to(start).collect();
+        assert_eq!(diagonal.len(), 4);
+        assert_eq!(diagonal[1], Point { x: 2, y: 2 });
+        assert_eq!(start.line_to(start).collect::<Vec<_>>(), vec![start]);
+
+        // Every step moves at most one cell along each axis.
+        let steep: Vec<_> = start.line_to(Point { x: -2, y: 7 }).collect();
+        assert_eq!(steep.len(), 8);
+        assert!(steep
+            .windows(2)
+            .all(|w| (w[1].x - w[0].x).abs() <= 1 && w[1].y - w[0].y == 1));
+    }
+
+    #[test]
+    fn test_lattice_line_to() {
+        let start = Point { x: 1, y: 1 };
+        let line: Vec<_> = start.lattice_line_to(Point { x: 7, y: 10 }).collect();
+        assert_eq!(
+            line,
+            vec![
+                Point { x: 1, y: 1 },
+                Point { x: 3, y: 4 },
+                Point { x: 5, y: 7 },
+                Point { x: 7, y: 10 },
+            ]
+        );
+        assert_eq!(start.lattice_line_to(Point { x: 2, y: 3 }).count(), 2);
+        assert_eq!(
+            start.lattice_line_to(start).collect::<Vec<_>>(),
+            vec![start]
+        );
+        assert_eq!(start.lattice_line_to(Point { x: 1, y: -2 }).count(), 4);
+    }
+
+    #[test]
+    fn test_direction_to() {
+        let origin = Point { x: 0, y: 0 };
+        assert_eq!(
+            origin.direction_to(Point { x: 6, y: -4 }),
+            Some(Point { x: 3, y: -2 })
+        );
+        assert_eq!(
+            origin.direction_to(Point { x: 0, y: 5 }),
+            Some(Point { x: 0, y: 1 })
+        );
+        assert_eq!(
+            Point { x: 4, y: 4 }.direction_to(Point { x: 1, y: 1 }),
+            Some(Point { x: -1, y: -1 })
+        );
+        assert_eq!(origin.direction_to(origin), None);
+        assert_eq!(
+            origin.direction_to(Point {
+                x: isize::MIN,
+                y: 0
+            }),
+            Some(Point { x: -1, y: 0 })
+        );
+    }
+
+    #[test]
+    fn test_line_through() {
+        let bounds = Rect::new(Point { x: 0, y: 0 }, Point { x: 9, y: 9 });
+
+        // The difference (4, 2) is not primitive, so (3, 2) and (5, 3) lie on the line too.
+        let line: Vec<_> = Point { x: 1, y: 1 }
+            .line_through(Point { x: 5, y: 3 }, bounds)
+            .collect();
+        assert_eq!(
+            line,
+            vec![
+                Point { x: 1, y: 1 },
+                Point { x: 3, y: 2 },
+                Point { x: 5, y: 3 },
+                Point { x: 7, y: 4 },
+                Point { x: 9, y: 5 },
+            ]
+        );
+
+        let reversed: Vec<_> = Point { x: 5, y: 3 }
+            .line_through(Point { x: 1, y: 1 }, bounds)
+            .collect();
+        assert_eq!(reversed, line.into_iter().rev().collect::<Vec<_>>());
+
+        // Points outside the bounds still define the line.
+        let column: Vec<_> = Point { x: 2, y: -20 }
+            .line_through(Point { x: 2, y: -10 }, bounds)
+            .collect();
+        assert_eq!(column.len(), 10);
+        assert_eq!(column[0], Point { x: 2, y: 0 });
+
+        let missed = Point { x: 20, y: 0 }.line_through(Point { x: 20, y: 1 }, bounds);
+        assert_eq!(missed.count(), 0);
+    }
+
+    #[test]
+    fn test_is_collinear() {
+        let a = Point { x: 0, y: 0 };
+        assert!(a.is_collinear(Point { x: 2, y: 1 }, Point { x: -4, y: -2 }));
+        assert!(a.is_collinear(a, Point { x: 7, y: 3 }));
+        assert!(!a.is_collinear(Point { x: 2, y: 1 }, Point { x: 4, y: 3 }));
+    }
+
+    #[test]
+    fn test_within_manhattan() {
+        let center = Point { x: 2, y: 2 };
+        let diamond: Vec<_> = center.within_manhattan(1).collect();
+        assert_eq!(
+            diamond,
+            vec![
+                (Point { x: 2, y: 1 }, 1),
+                (Point { x: 1, y: 2 }, 1),
+                (Point { x: 2, y: 2 }, 0),
+                (Point { x: 3, y: 2 }, 1),
+                (Point { x: 2, y: 3 }, 1),
+            ]
+        );
+        assert_eq!(
+            center.within_manhattan(0).collect::<Vec<_>>(),
+            vec![(center, 0)]
+        );
+
+        // A diamond of radius r holds 2r(r + 1) + 1 points, none of them twice.
+        let points: HashSet<_> = center.within_manhattan(20).map(|(p, _)| p).collect();
+        assert_eq!(points.len(), 2 * 20 * 21 + 1);
+    }
+
+    #[test]
+    fn test_within_chebyshev() {
+        let center = Point { x: 0, y: 0 };
+        let square: Vec<_> = center.within_chebyshev(1).map(|(_, d)| d).collect();
+        assert_eq!(square, vec![1, 1, 1, 1, 0, 1, 1, 1, 1]);
+
+        let points: HashSet<_> = center.within_chebyshev(3).map(|(p, _)| p).collect();
+        assert_eq!(points.len(), 49);
+        assert!(center
+            .within_chebyshev(3)
+            .all(|(p, d)| p.x.abs().max(p.y.abs()) as usize == d));
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::SouthEast.turn_right(), Direction::SouthWest);
+        assert_eq!(Direction::NorthEast.turn_left(), Direction::NorthWest);
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(
+                direction.turn_right_45().turn_right_45(),
+                direction.turn_right()
+            );
+            assert_eq!(direction.turn_left_45().turn_right_45(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+        }
+        assert_eq!(Direction::NorthEast.turn_left_45(), Direction::North);
+        assert_eq!(Direction::NorthWest.turn_right_45(), Direction::North);
+        assert_eq!(Direction::SouthWest.opposite(), Direction::NorthEast);
+    }
+
+    #[test]
+    fn test_direction_sets() {
+        assert!(Direction::CARDINAL.iter().all(Direction::is_cardinal));
+        assert!(!Direction::DIAGONAL.iter().any(Direction::is_cardinal));
+
+        // Opposite directions cancel each other out.
+        for direction in Direction::ALL {
+            let (dx, dy) = direction.delta();
+            let (ox, oy) = direction.opposite().delta();
+            assert_eq!((dx + ox, dy + oy), (0, 0));
+        }
+    }
+
+    #[test]
+    fn test_direction_try_from_char() {
+        let arrows: Vec<_> = "^>v<".chars().map(Direction::try_from).collect();
+        let compass: Vec<_> = "NESW".chars().map(Direction::try_from).collect();
+        let moves: Vec<_> = "URDL".chars().map(Direction::try_from).collect();
+        let expected: Vec<_> = Direction::CARDINAL.into_iter().map(Ok).collect();
+
+        assert_eq!(arrows, expected);
+        assert_eq!(compass, expected);
+        assert_eq!(moves, expected);
+
+        let error = Direction::try_from('x').unwrap_err();
+        assert_eq!(error, ParseDirectionError { found: 'x' });
+        assert_eq!(error.to_string(), "invalid direction 'x'");
+    }
+
+    #[test]
+    fn test_spiral() {
+        let center = Point { x: 5, y: 5 };
+        let spiral: Vec<_> = center
+            .spiral()
+            .take(10)
+            .map(|p| (p.x - center.x, p.y - center.y))
+            .collect();
+        assert_eq!(
+            spiral,
+            vec![
+                (0, 0),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (2, -1),
+            ]
+        );
+
+        // The first 25 points are exactly the 5x5 square around the center.
+        let mut square: Vec<_> = Point { x: 0, y: 0 }.spiral().take(25).collect();
+        square.sort_by_key(|p| (p.y, p.x));
+        square.dedup();
+        assert_eq!(square.len(), 25);
+        assert!(square.iter().all(|p| p.x.abs() <= 2 && p.y.abs() <= 2));
+    }
 }
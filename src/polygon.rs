@@ -0,0 +1,186 @@
+use crate::{gcd, Coordinate, GenericPoint};
+
+/// A closed polygon on the integer lattice, given by its vertices in order.
+///
+/// The vertices may use any [`Coordinate`] type, and all calculations are done in `i128`.
+/// The area of a polygon spanning most of the `i64` range does not fit even in that, so the
+/// helpers return `None` when they overflow instead of panicking.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon<T = isize> {
+    pub vertices: Vec<GenericPoint<T>>,
+}
+
+impl<T> Polygon<T>
+where
+    T: Coordinate,
+{
+    pub fn new(vertices: Vec<GenericPoint<T>>) -> Self {
+        Self { vertices }
+    }
+
+    /// Returns twice the signed area (shoelace formula). The result is positive when the
+    /// vertices run clockwise on screen, i.e. with `y` pointing down.
+    pub fn signed_double_area(&self) -> Option<i128> {
+        self.edges().try_fold(0_i128, |sum, (a, b)| {
+            let cross =
+                a.x.to_i128()
+                    .checked_mul(b.y.to_i128())?
+                    .checked_sub(b.x.to_i128().checked_mul(a.y.to_i128())?)?;
+            sum.checked_add(cross)
+        })
+    }
+
+    /// Returns twice the area, which is always an integer for lattice polygons.
+    pub fn double_area(&self) -> Option<i128> {
+        self.signed_double_area()?.checked_abs()
+    }
+
+    /// Returns the number of lattice points on the boundary, which equals the perimeter
+    /// for polygons made of horizontal and vertical edges.
+    pub fn boundary_length(&self) -> Option<i128> {
+        self.edges().try_fold(0_i128, |sum, (a, b)| {
+            let dx = b.x.to_i128().checked_sub(a.x.to_i128())?;
+            let dy = b.y.to_i128().checked_sub(a.y.to_i128())?;
+            sum.checked_add(i128::try_from(gcd(dx, dy)).ok()?)
+        })
+    }
+
+    /// Returns the number of lattice points strictly inside the polygon (Pick's theorem).
+    pub fn interior_points(&self) -> Option<i128> {
+        Some((self.double_area()? - self.boundary_length()? + 2) / 2)
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (GenericPoint<T>, GenericPoint<T>)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+}
+
+impl<T> From<Vec<GenericPoint<T>>> for Polygon<T>
+where
+    T: Coordinate,
+{
+    fn from(vertices: Vec<GenericPoint<T>>) -> Self {
+        Self::new(vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    fn square(size: isize) -> Polygon {
+        Polygon::new(vec![
+            Point { x: 0, y: 0 },
+            Point { x: size, y: 0 },
+            Point { x: size, y: size },
+            Point { x: 0, y: size },
+        ])
+    }
+
+    #[test]
+    fn test_area() {
+        let polygon = square(4);
+        assert_eq!(polygon.signed_double_area(), Some(32));
+        assert_eq!(polygon.double_area(), Some(32));
+
+        let mut reversed = polygon.clone();
+        reversed.vertices.reverse();
+        assert_eq!(reversed.signed_double_area(), Some(-32));
+        assert_eq!(reversed.double_area(), Some(32));
+    }
+
+    #[test]
+    fn test_boundary_and_interior() {
+        let polygon = square(4);
+        assert_eq!(polygon.boundary_length(), Some(16));
+        assert_eq!(polygon.interior_points(), Some(9));
+
+        // A triangle with a diagonal edge passing through the lattice points (1, 1) and (2, 2).
+        let triangle = Polygon::new(vec![
+            Point { x: 0, y: 0 },
+            Point { x: 3, y: 0 },
+            Point { x: 3, y: 3 },
+        ]);
+        assert_eq!(triangle.double_area(), Some(9));
+        assert_eq!(triangle.boundary_length(), Some(9));
+        assert_eq!(triangle.interior_points(), Some(1));
+    }
+
+    #[test]
+    fn test_huge_coordinates() {
+        let polygon = square(1 << 40);
+        assert_eq!(polygon.double_area(), Some(2 * (1 << 80)));
+        assert_eq!(polygon.boundary_length(), Some(4 * (1 << 40)));
+        assert_eq!(
+            polygon.interior_points(),
+            Some(((1_i128 << 40) - 1) * ((1_i128 << 40) - 1))
+        );
+
+        let polygon = square(1 << 62);
+        assert_eq!(polygon.double_area(), Some(1 << 125));
+    }
+
+    #[test]
+    fn test_area_overflow() {
+        // Twice the area is about 2^129, which does not fit in an i128.
+        let polygon = Polygon::new(vec![
+            Point {
+                x: isize::MIN,
+                y: isize::MIN,
+            },
+            Point {
+                x: isize::MAX,
+                y: isize::MIN,
+            },
+            Point {
+                x: isize::MAX,
+                y: isize::MAX,
+            },
+            Point {
+                x: isize::MIN,
+                y: isize::MAX,
+            },
+        ]);
+        assert_eq!(polygon.signed_double_area(), None);
+        assert_eq!(polygon.double_area(), None);
+        assert_eq!(polygon.interior_points(), None);
+        assert_eq!(polygon.boundary_length(), Some(4 * (u64::MAX as i128)));
+    }
+
+    #[test]
+    fn test_i128_vertices() {
+        let corner = |x: i128, y: i128| GenericPoint { x, y };
+        let square = |size: i128| {
+            Polygon::new(vec![
+                corner(-size, -size),
+                corner(size, -size),
+                corner(size, size),
+                corner(-size, size),
+            ])
+        };
+
+        // The sides are 2^62 long, beyond what an isize polygon can hold.
+        let polygon = square(1 << 61);
+        assert_eq!(polygon.double_area(), Some(1 << 125));
+        assert_eq!(polygon.boundary_length(), Some(1 << 64));
+        assert_eq!(
+            polygon.interior_points(),
+            Some(((1 << 62) - 1) * ((1 << 62) - 1))
+        );
+
+        let polygon = square(1 << 100);
+        assert_eq!(polygon.double_area(), None);
+        assert_eq!(polygon.boundary_length(), Some(1 << 103));
+
+        let polygon = square(i128::MAX);
+        assert_eq!(polygon.boundary_length(), None);
+
+        // The edge back to the origin is 2^127 long, one more than an i128 holds.
+        let polygon = Polygon::new(vec![corner(0, 0), corner(i128::MIN, 0)]);
+        assert_eq!(polygon.boundary_length(), None);
+    }
+}
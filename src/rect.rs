@@ -0,0 +1,237 @@
+use crate::{Coordinate, GenericPoint, Point};
+
+/// An axis-aligned rectangle of points, with both corners included.
+///
+/// A rectangle whose `max` lies left of or above its `min` holds no points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<T = isize> {
+    /// The top left corner.
+    pub min: GenericPoint<T>,
+    /// The bottom right corner.
+    pub max: GenericPoint<T>,
+}
+
+impl<T> Rect<T>
+where
+    T: Coordinate,
+{
+    pub fn new(min: GenericPoint<T>, max: GenericPoint<T>) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns the smallest rectangle containing every point, or `None` if there are none.
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = GenericPoint<T>>,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |rect, point| {
+            rect.union(&Self::new(point, point))
+        }))
+    }
+
+    /// The number of columns, or `None` if that does not fit in `T`.
+    pub fn width(&self) -> Option<T> {
+        span(self.min.x, self.max.x)
+    }
+
+    /// The number of rows, or `None` if that does not fit in `T`.
+    pub fn height(&self) -> Option<T> {
+        span(self.min.y, self.max.y)
+    }
+
+    /// Whether the rectangle holds no points.
+    pub fn is_empty(&self) -> bool {
+        self.max.x < self.min.x || self.max.y < self.min.y
+    }
+
+    /// The number of points in the rectangle, or `None` if that does not fit in `T`.
+    pub fn area(&self) -> Option<T> {
+        if self.is_empty() {
+            return Some(T::ZERO);
+        }
+        self.width()?.checked_mul(self.height()?)
+    }
+
+    pub fn contains(&self, point: &GenericPoint<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Returns the points both rectangles contain, or `None` if they do not overlap.
+    pub fn intersect(&self, other: &Rect<T>) -> Option<Rect<T>> {
+        let rect = Self::new(
+            GenericPoint {
+                x: self.min.x.max(other.min.x),
+                y: self.min.y.max(other.min.y),
+            },
+            GenericPoint {
+                x: self.max.x.min(other.max.x),
+                y: self.max.y.min(other.max.y),
+            },
+        );
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    /// Returns the smallest rectangle containing both rectangles. Empty rectangles add no
+    /// points, so the union with one is the other rectangle.
+    pub fn union(&self, other: &Rect<T>) -> Rect<T> {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        Self::new(
+            GenericPoint {
+                x: self.min.x.min(other.min.x),
+                y: self.min.y.min(other.min.y),
+            },
+            GenericPoint {
+                x: self.max.x.max(other.max.x),
+                y: self.max.y.max(other.max.y),
+            },
+        )
+    }
+
+    /// Grows the rectangle by `margin` on every side, or shrinks it if `margin` is negative.
+    /// Returns `None` if a corner would not fit in `T`.
+    pub fn expand(&self, margin: T) -> Option<Rect<T>> {
+        let shrink = T::ZERO.checked_sub(margin)?;
+        Some(Self::new(
+            self.min.checked_offset(shrink, shrink)?,
+            self.max.checked_offset(margin, margin)?,
+        ))
+    }
+}
+
+/// The number of values from `min` to `max`, or `None` if that does not fit in `T`.
+fn span<T: Coordinate>(min: T, max: T) -> Option<T> {
+    if max < min {
+        Some(T::ZERO)
+    } else {
+        max.checked_sub(min)?.checked_add(T::ONE)
+    }
+}
+
+impl Rect {
+    /// Returns every point of the rectangle in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Rect { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point { x, y }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x1: isize, y1: isize, x2: isize, y2: isize) -> Rect {
+        Rect::new(Point { x: x1, y: y1 }, Point { x: x2, y: y2 })
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = [Point { x: 3, y: -1 }, Point { x: 0, y: 4 }, Point { x: 5, y: 2 }];
+        assert_eq!(Rect::from_points(points), Some(rect(0, -1, 5, 4)));
+        assert_eq!(Rect::<isize>::from_points([]), None);
+
+        let single = Rect::from_points([Point { x: 2, y: 2 }]).unwrap();
+        assert_eq!(single.area(), Some(1));
+    }
+
+    #[test]
+    fn test_contains_and_area() {
+        let r = rect(1, 1, 3, 2);
+        assert_eq!(
+            (r.width(), r.height(), r.area()),
+            (Some(3), Some(2), Some(6))
+        );
+        assert!(r.contains(&Point { x: 3, y: 2 }));
+        assert!(!r.contains(&Point { x: 0, y: 1 }));
+
+        let empty = rect(0, 0, -1, 5);
+        assert_eq!(empty.width(), Some(0));
+        assert_eq!(empty.area(), Some(0));
+        assert!(empty.is_empty());
+        assert!(!empty.contains(&Point { x: 0, y: 0 }));
+        assert_eq!(empty.points().count(), 0);
+    }
+
+    #[test]
+    fn test_intersect_and_union() {
+        let a = rect(0, 0, 4, 4);
+        let b = rect(3, -2, 6, 1);
+        assert_eq!(a.intersect(&b), Some(rect(3, 0, 4, 1)));
+        assert_eq!(a.union(&b), rect(0, -2, 6, 4));
+        assert_eq!(a.intersect(&rect(5, 5, 6, 6)), None);
+
+        let empty = rect(0, 0, -1, -1);
+        assert_eq!(empty.union(&rect(5, 5, 6, 6)), rect(5, 5, 6, 6));
+        assert_eq!(rect(5, 5, 6, 6).union(&empty), rect(5, 5, 6, 6));
+        assert_eq!(empty.intersect(&a), None);
+    }
+
+    #[test]
+    fn test_generic_rect() {
+        let a: Rect<i128> = Rect::new(
+            GenericPoint {
+                x: -(1 << 100),
+                y: 0,
+            },
+            GenericPoint { x: 1 << 100, y: 1 },
+        );
+        assert_eq!(a.width(), Some((1 << 101) + 1));
+        assert_eq!(a.area(), Some(((1 << 101) + 1) * 2));
+
+        let huge: Rect<i64> = Rect::new(
+            GenericPoint { x: 0, y: 0 },
+            GenericPoint {
+                x: 1 << 40,
+                y: 1 << 40,
+            },
+        );
+        assert_eq!(huge.area(), None);
+        assert_eq!(huge.expand(-(1 << 20)).unwrap().area(), None);
+        assert!(a.contains(&GenericPoint { x: 1 << 99, y: 1 }));
+        assert_eq!(a.expand(1).unwrap().height(), Some(4));
+
+        // Every point fits in an i8, but there are 256 of them on a row.
+        let row: Rect<i8> = Rect::new(
+            GenericPoint { x: -128, y: 0 },
+            GenericPoint { x: 127, y: 0 },
+        );
+        assert_eq!(row.width(), None);
+        assert_eq!(row.height(), Some(1));
+        assert_eq!(row.area(), None);
+        assert_eq!(row.expand(1), None);
+        assert_eq!(row.expand(i8::MIN), None);
+
+        let b = Rect::new(GenericPoint { x: 0, y: 1 }, GenericPoint { x: 5, y: 9 });
+        assert_eq!(
+            a.intersect(&b),
+            Some(Rect::new(
+                GenericPoint { x: 0, y: 1 },
+                GenericPoint { x: 5, y: 1 }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_expand_and_points() {
+        let r = rect(2, 2, 2, 3);
+        let expanded = r.expand(1).unwrap();
+        assert_eq!(expanded, rect(1, 1, 3, 4));
+        assert_eq!(expanded.expand(-1), Some(r));
+        assert_eq!(
+            expanded.points().take(4).collect::<Vec<_>>(),
+            vec![
+                Point { x: 1, y: 1 },
+                Point { x: 2, y: 1 },
+                Point { x: 3, y: 1 },
+                Point { x: 1, y: 2 },
+            ]
+        );
+        assert_eq!(r.expand(-1).unwrap().area(), Some(0));
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// The shape of the sequence produced by repeatedly applying a step function:
+/// `start` steps lead into a loop that repeats every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Maps any step onto the earliest step that yields the same state.
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.start {
+            return step;
+        }
+        self.start + (step - self.start) % self.length
+    }
+}
+
+/// A deterministic simulation whose cycle has been detected, so that the state at any step
+/// can be reached without simulating every step in between.
+pub struct Simulation<S, F> {
+    initial: S,
+    step: F,
+    cycle: Cycle,
+}
+
+/// Detects the cycle of `step` applied to `initial` using Brent's algorithm.
+///
+/// The state space reachable from `initial` must be finite, otherwise this never returns.
+pub fn simulate<S, F>(initial: S, step: F) -> Simulation<S, F>
+where
+    S: Hash + Eq + Clone,
+    F: Fn(&S) -> S,
+{
+    let cycle = find_cycle(&initial, &step);
+    Simulation {
+        initial,
+        step,
+        cycle,
+    }
+}
+
+impl<S, F> Simulation<S, F>
+where
+    S: Hash + Eq + Clone,
+    F: Fn(&S) -> S,
+{
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// Returns the state after `n` steps.
+    pub fn state_at(&self, n: usize) -> S {
+        let mut state = self.initial.clone();
+        for _ in 0..self.cycle.reduce(n) {
+            state = (self.step)(&state);
+        }
+        state
+    }
+
+    /// Returns `value(state)` for the state after `n` steps.
+    pub fn value_at<V>(&self, n: usize, value: impl Fn(&S) -> V) -> V {
+        value(&self.state_at(n))
+    }
+}
+
+/// A state together with its hash, so that most comparisons only compare the hashes.
+#[derive(Clone)]
+struct Fingerprint<S> {
+    hash: u64,
+    state: S,
+}
+
+impl<S: Hash + Eq> Fingerprint<S> {
+    fn new(state: S) -> Self {
+        let mut hasher = DefaultHasher::new();
+        state.hash(&mut hasher);
+        Self {
+            hash: hasher.finish(),
+            state,
+        }
+    }
+
+    fn advance(&self, step: &impl Fn(&S) -> S) -> Self {
+        Self::new(step(&self.state))
+    }
+
+    fn same(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.state == other.state
+    }
+}
+
+fn find_cycle<S, F>(initial: &S, step: &F) -> Cycle
+where
+    S: Hash + Eq + Clone,
+    F: Fn(&S) -> S,
+{
+    // Find the cycle length: the tortoise teleports to the hare at every power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = Fingerprint::new(initial.clone());
+    let mut hare = tortoise.advance(step);
+
+    while !tortoise.same(&hare) {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = hare.advance(step);
+        length += 1;
+    }
+
+    // Find the cycle start: walk two pointers `length` steps apart until they meet.
+    let mut tortoise = Fingerprint::new(initial.clone());
+    let mut hare = Fingerprint::new(initial.clone());
+    for _ in 0..length {
+        hare = hare.advance(step);
+    }
+
+    let mut start = 0;
+    while !tortoise.same(&hare) {
+        tortoise = tortoise.advance(step);
+        hare = hare.advance(step);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    #[test]
+    fn test_pure_cycle() {
+        let simulation = simulate(0_u32, |x| (x + 1) % 5);
+        assert_eq!(
+            simulation.cycle(),
+            Cycle {
+                start: 0,
+                length: 5
+            }
+        );
+        assert_eq!(simulation.state_at(1_000_000_002), 2);
+    }
+
+    #[test]
+    fn test_cycle_with_tail() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3 -> ...
+        let simulation = simulate(0_u32, |&x| if x == 6 { 3 } else { x + 1 });
+        assert_eq!(
+            simulation.cycle(),
+            Cycle {
+                start: 3,
+                length: 4
+            }
+        );
+
+        assert_eq!(simulation.state_at(2), 2);
+        assert_eq!(simulation.state_at(7), 3);
+        assert_eq!(
+            simulation.state_at(1_000_000_000),
+            3 + (1_000_000_000 - 3) % 4
+        );
+        assert_eq!(simulation.value_at(10, |x| x * 10), 60);
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let simulation = simulate(10_u32, |&x| x.saturating_sub(3));
+        assert_eq!(
+            simulation.cycle(),
+            Cycle {
+                start: 4,
+                length: 1
+            }
+        );
+        assert_eq!(simulation.state_at(usize::MAX), 0);
+    }
+
+    #[test]
+    fn test_grid_state() {
+        // A single marker moving east, wrapping around a 3 wide grid.
+        let mut grid = Grid::<bool>::new_empty(3, 1);
+        grid.set(Point { x: 0, y: 0 }, true);
+
+        let simulation = simulate(grid, |grid| {
+            let mut next = Grid::<bool>::new_empty(grid.width, grid.height);
+            for (point, value) in grid.iter().with_points() {
+                if value {
+                    next.set(
+                        Point {
+                            x: (point.x + 1) % grid.width,
+                            y: point.y,
+                        },
+                        true,
+                    );
+                }
+            }
+            next
+        });
+
+        assert_eq!(
+            simulation.cycle(),
+            Cycle {
+                start: 0,
+                length: 3
+            }
+        );
+        let state = simulation.state_at(1_000_000_000);
+        assert!(state.get(Point { x: 1, y: 0 }));
+    }
+}